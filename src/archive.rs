@@ -0,0 +1,489 @@
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::diff::diff;
+use crate::filter::ComponentFilter;
+use crate::models::{ComponentStatus, Indicator, SummaryInfo};
+
+/// The longest time between two snapshots for the status to be treated as unchanged between them.
+/// Longer gaps, e.g. while nothing was recording, are left out of the history.
+const MAX_GAP: TimeDelta = TimeDelta::minutes(15);
+
+/// A local archive of polled summaries, stored as JSON Lines with one file per status page.
+#[derive(Debug, Clone)]
+pub struct Archive {
+    dir: PathBuf,
+}
+
+/// A summary along with the period it was seen unchanged.
+#[derive(Deserialize, Serialize, Debug)]
+pub struct Snapshot {
+    /// When the status was first seen.
+    pub recorded_at: DateTime<Utc>,
+
+    /// The last time the status was seen unchanged.
+    pub checked_at: DateTime<Utc>,
+
+    pub summary: SummaryInfo,
+}
+
+#[derive(Serialize)]
+struct SnapshotRef<'a> {
+    recorded_at: DateTime<Utc>,
+    checked_at: DateTime<Utc>,
+    summary: &'a SummaryInfo,
+}
+
+impl Archive {
+    pub fn new(dir: impl Into<PathBuf>) -> Archive {
+        Archive { dir: dir.into() }
+    }
+
+    /// The `history` directory in the user's data dir, e.g. `~/.local/share/github-status/history`.
+    pub fn default_dir() -> Option<PathBuf> {
+        dirs::data_dir().map(|d| d.join("github-status").join("history"))
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn path(&self, base_url: &str) -> PathBuf {
+        let name: String = base_url
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+
+        self.dir.join(format!("{name}.jsonl"))
+    }
+
+    /// Opens the archive of the status page at `base_url` for appending.
+    pub fn recorder(&self, base_url: &str) -> Recorder {
+        Recorder {
+            path: self.path(base_url),
+            last: None,
+        }
+    }
+
+    /// Reads the snapshots of the status page at `base_url`, oldest first. Lines that can't be
+    /// read, e.g. one cut short by a crash, are skipped.
+    pub fn snapshots(&self, base_url: &str) -> Result<Vec<Snapshot>> {
+        let path = self.path(base_url);
+        let data = match fs::read_to_string(&path) {
+            Ok(data) => data,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => {
+                return Err(e).with_context(|| format!("Unable to read {}", path.display()));
+            }
+        };
+
+        let mut snapshots: Vec<Snapshot> = data
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect();
+        snapshots.sort_by_key(|s| s.recorded_at);
+
+        Ok(snapshots)
+    }
+}
+
+/// Appends summaries to the archive of one status page.
+#[derive(Debug)]
+pub struct Recorder {
+    path: PathBuf,
+
+    /// The last snapshot in the file, kept so an unchanged status can be merged into it.
+    last: Option<LastSnapshot>,
+}
+
+#[derive(Debug)]
+struct LastSnapshot {
+    /// The byte offset of the snapshot in the file.
+    offset: u64,
+
+    /// The length of the file after the snapshot was written, to notice other writers.
+    len: u64,
+
+    snapshot: Snapshot,
+}
+
+impl Recorder {
+    /// Records the summary polled at `at`. If nothing changed since the last snapshot it is
+    /// extended instead of appending a new one, which keeps the archive compact.
+    pub fn record(&mut self, summary: &SummaryInfo, at: DateTime<Utc>) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .read(true)
+            .write(true)
+            .open(&self.path)
+            .with_context(|| format!("Unable to open {}", self.path.display()))?;
+
+        // Several processes can record the same status page, e.g. `record` alongside
+        // `watch --record`, so the file is locked until the snapshot is written, and the last
+        // snapshot is read again if another one wrote to it since.
+        file.lock()
+            .with_context(|| format!("Unable to lock {}", self.path.display()))?;
+        let mut end = file.metadata()?.len();
+        if self.last.as_ref().map(|last| last.len) != Some(end) {
+            (end, self.last) = read_last(&mut file)
+                .with_context(|| format!("Unable to read {}", self.path.display()))?;
+        }
+
+        let (offset, recorded_at) = match &self.last {
+            Some(last) if diff(&last.snapshot.summary, summary).is_empty() => {
+                (last.offset, last.snapshot.recorded_at)
+            }
+            _ => (end, at),
+        };
+        let line = serde_json::to_string(&SnapshotRef {
+            recorded_at,
+            checked_at: at,
+            summary,
+        })?;
+
+        file.set_len(offset)?;
+        file.seek(SeekFrom::Start(offset))?;
+        writeln!(file, "{line}")?;
+
+        self.last = Some(LastSnapshot {
+            offset,
+            len: offset + line.len() as u64 + 1,
+            snapshot: serde_json::from_str(&line)?,
+        });
+
+        Ok(())
+    }
+}
+
+/// Reads the last snapshot in the file, if it can be read, along with the end of the last complete
+/// line. Anything after it was cut short, e.g. by a crash, and is overwritten by the next snapshot.
+fn read_last(file: &mut File) -> Result<(u64, Option<LastSnapshot>)> {
+    let mut data = Vec::new();
+    file.seek(SeekFrom::Start(0))?;
+    file.read_to_end(&mut data)?;
+
+    let end = data.iter().rposition(|&b| b == b'\n').map_or(0, |i| i + 1);
+    let lines = &data[..end];
+    let trimmed = lines.strip_suffix(b"\n").unwrap_or(lines);
+    let offset = trimmed
+        .iter()
+        .rposition(|&b| b == b'\n')
+        .map_or(0, |i| i + 1);
+
+    let last = serde_json::from_slice(&trimmed[offset..])
+        .ok()
+        .map(|snapshot| LastSnapshot {
+            offset: offset as u64,
+            len: end as u64,
+            snapshot,
+        });
+
+    Ok((end as u64, last))
+}
+
+/// The status of the selected components over a period, from the archive.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct History {
+    pub entries: Vec<HistoryEntry>,
+}
+
+/// A period during which the status didn't change.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct HistoryEntry {
+    pub from: DateTime<Utc>,
+    pub until: DateTime<Utc>,
+
+    /// The overall indicator, or the worst status of the selected components.
+    pub indicator: Indicator,
+
+    /// The page's description, or the description of the indicator if components were selected.
+    pub description: String,
+    pub components: Vec<ComponentState>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct ComponentState {
+    pub name: String,
+    pub status: ComponentStatus,
+}
+
+impl HistoryEntry {
+    /// Returns `true` if any of the components weren't operational, not counting maintenance.
+    pub fn is_degraded(&self) -> bool {
+        self.components.iter().any(ComponentState::is_degraded)
+    }
+}
+
+impl ComponentState {
+    /// Returns `true` if the component wasn't operational, not counting maintenance.
+    pub fn is_degraded(&self) -> bool {
+        !matches!(
+            self.status,
+            ComponentStatus::Operational | ComponentStatus::UnderMaintenance
+        )
+    }
+}
+
+impl History {
+    /// The history of the components selected by `filter` from `since` until `until`, merging
+    /// periods where they didn't change.
+    pub fn new(
+        snapshots: &[Snapshot],
+        since: DateTime<Utc>,
+        until: DateTime<Utc>,
+        filter: &ComponentFilter,
+    ) -> History {
+        let mut entries: Vec<HistoryEntry> = Vec::new();
+
+        for (i, snapshot) in snapshots.iter().enumerate() {
+            let end = match snapshots.get(i + 1) {
+                Some(next) if next.recorded_at - snapshot.checked_at <= MAX_GAP => next.recorded_at,
+                _ => snapshot.checked_at,
+            };
+            if end < since || snapshot.recorded_at > until {
+                continue;
+            }
+
+            let summary = &snapshot.summary;
+            // The page's description would describe components that were filtered out, and
+            // changes to them would split the entries.
+            let indicator = summary.indicator(filter);
+            let description = if filter.is_empty() {
+                summary.status.description.clone()
+            } else {
+                indicator.description().to_string()
+            };
+            let entry = HistoryEntry {
+                from: snapshot.recorded_at.max(since),
+                until: end.min(until),
+                indicator,
+                description,
+                components: filter
+                    .select(&summary.components)
+                    .iter()
+                    .filter(|c| !c.group)
+                    .map(|c| ComponentState {
+                        name: c.name.clone(),
+                        status: c.status.clone(),
+                    })
+                    .collect(),
+            };
+
+            match entries.last_mut() {
+                Some(last)
+                    if last.until == entry.from
+                        && last.indicator == entry.indicator
+                        && last.description == entry.description
+                        && last.components == entry.components =>
+                {
+                    last.until = entry.until;
+                }
+                _ => entries.push(entry),
+            }
+        }
+
+        History { entries }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use chrono::{DateTime, TimeDelta, Utc};
+
+    use super::{Archive, History};
+    use crate::filter::ComponentFilter;
+    use crate::fixtures::summary;
+    use crate::models::ComponentStatus;
+
+    fn at(minute: i64) -> DateTime<Utc> {
+        "2024-01-10T03:00:00Z".parse::<DateTime<Utc>>().unwrap() + TimeDelta::minutes(minute)
+    }
+
+    #[test]
+    fn test_record_and_history() {
+        let dir = env::temp_dir().join(format!("github-status-archive-{}", std::process::id()));
+        let archive = Archive::new(&dir);
+        let base_url = "https://www.githubstatus.com";
+
+        let mut recorder = archive.recorder(base_url);
+        recorder
+            .record(&summary("none", "operational", ""), at(0))
+            .unwrap();
+        recorder
+            .record(&summary("none", "operational", ""), at(1))
+            .unwrap();
+        recorder
+            .record(&summary("none", "major_outage", ""), at(2))
+            .unwrap();
+
+        // Reopening continues compacting into the last snapshot.
+        let mut recorder = archive.recorder(base_url);
+        recorder
+            .record(&summary("none", "major_outage", ""), at(3))
+            .unwrap();
+        recorder
+            .record(&summary("none", "operational", ""), at(4))
+            .unwrap();
+        // Nothing was recorded for an hour.
+        recorder
+            .record(&summary("none", "operational", ""), at(64))
+            .unwrap();
+
+        let snapshots = archive.snapshots(base_url).unwrap();
+        assert_eq!(
+            snapshots
+                .iter()
+                .map(|s| (s.recorded_at, s.checked_at))
+                .collect::<Vec<_>>(),
+            vec![(at(0), at(1)), (at(2), at(3)), (at(4), at(64))]
+        );
+        assert!(archive
+            .snapshots("https://status.npmjs.org")
+            .unwrap()
+            .is_empty());
+
+        let history = History::new(
+            &snapshots,
+            at(0),
+            at(90),
+            &ComponentFilter::new(["Actions"]),
+        );
+        assert_eq!(
+            history
+                .entries
+                .iter()
+                .map(|e| (e.from, e.until, e.components[0].status.clone()))
+                .collect::<Vec<_>>(),
+            vec![
+                (at(0), at(2), ComponentStatus::Operational),
+                (at(2), at(4), ComponentStatus::MajorOutage),
+                (at(4), at(64), ComponentStatus::Operational),
+            ]
+        );
+        assert!(history.entries[1].is_degraded());
+
+        // Only the periods covering the time are included.
+        let history = History::new(&snapshots, at(3), at(3), &ComponentFilter::new(["Actions"]));
+        assert_eq!(history.entries.len(), 1);
+        assert_eq!(history.entries[0].indicator.as_str(), "critical");
+        assert_eq!(history.entries[0].description, "Major Service Outage");
+
+        // Changes to the components that were filtered out don't show up.
+        let history = History::new(
+            &snapshots,
+            at(0),
+            at(90),
+            &ComponentFilter::new(["Git Operations"]),
+        );
+        assert_eq!(history.entries.len(), 1);
+        assert_eq!(history.entries[0].description, "All Systems Operational");
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_several_recorders() {
+        let dir = env::temp_dir().join(format!("github-status-recorders-{}", std::process::id()));
+        let archive = Archive::new(&dir);
+        let base_url = "https://www.githubstatus.com";
+
+        let mut first = archive.recorder(base_url);
+        let mut second = archive.recorder(base_url);
+        first
+            .record(&summary("none", "operational", ""), at(0))
+            .unwrap();
+        second
+            .record(&summary("none", "operational", ""), at(1))
+            .unwrap();
+        second
+            .record(&summary("none", "major_outage", ""), at(2))
+            .unwrap();
+        // The first recorder extends the snapshot written by the second one.
+        first
+            .record(&summary("none", "major_outage", ""), at(3))
+            .unwrap();
+
+        let snapshots = archive.snapshots(base_url).unwrap();
+        assert_eq!(
+            snapshots
+                .iter()
+                .map(|s| (s.recorded_at, s.checked_at))
+                .collect::<Vec<_>>(),
+            vec![(at(0), at(1)), (at(2), at(3))]
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_partial_line() {
+        let dir = env::temp_dir().join(format!("github-status-partial-{}", std::process::id()));
+        let archive = Archive::new(&dir);
+        let base_url = "https://www.githubstatus.com";
+
+        archive
+            .recorder(base_url)
+            .record(&summary("none", "operational", ""), at(0))
+            .unwrap();
+        // A crash while writing the next snapshot.
+        let path = archive.path(base_url);
+        let mut data = fs::read(&path).unwrap();
+        data.extend_from_slice(br#"{"recorded_at":"2024-01-"#);
+        fs::write(&path, data).unwrap();
+
+        archive
+            .recorder(base_url)
+            .record(&summary("none", "operational", ""), at(1))
+            .unwrap();
+        archive
+            .recorder(base_url)
+            .record(&summary("none", "major_outage", ""), at(2))
+            .unwrap();
+
+        let snapshots = archive.snapshots(base_url).unwrap();
+        assert_eq!(
+            snapshots
+                .iter()
+                .map(|s| (s.recorded_at, s.checked_at))
+                .collect::<Vec<_>>(),
+            vec![(at(0), at(1)), (at(2), at(2))]
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_gaps() {
+        let snapshots = [at(0), at(30)]
+            .into_iter()
+            .map(|t| super::Snapshot {
+                recorded_at: t,
+                checked_at: t + TimeDelta::minutes(5),
+                summary: summary("none", "operational", ""),
+            })
+            .collect::<Vec<_>>();
+
+        let history = History::new(&snapshots, at(0), at(60), &ComponentFilter::default());
+        assert_eq!(
+            history
+                .entries
+                .iter()
+                .map(|e| (e.from, e.until, e.components.len()))
+                .collect::<Vec<_>>(),
+            vec![(at(0), at(5), 2), (at(30), at(35), 2)]
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// An on-disk cache of API responses, keyed by URL.
+#[derive(Debug, Clone)]
+pub struct Cache {
+    dir: PathBuf,
+    max_age: Duration,
+}
+
+/// A cached response along with the validators used to revalidate it.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub(crate) struct CacheEntry {
+    pub url: String,
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+    pub fetched_at: DateTime<Utc>,
+    pub body: String,
+}
+
+impl CacheEntry {
+    /// Returns `true` if the entry was fetched less than `max_age` ago.
+    pub fn is_fresh(&self, max_age: Duration) -> bool {
+        Utc::now()
+            .signed_duration_since(self.fetched_at)
+            .to_std()
+            .is_ok_and(|age| age < max_age)
+    }
+}
+
+impl Cache {
+    /// Creates a cache in `dir` whose entries are used without revalidating for `max_age`.
+    pub fn new(dir: impl Into<PathBuf>, max_age: Duration) -> Cache {
+        Cache {
+            dir: dir.into(),
+            max_age,
+        }
+    }
+
+    /// The `github-status` directory in the user's cache dir, e.g. `~/.cache/github-status`.
+    pub fn default_dir() -> Option<PathBuf> {
+        dirs::cache_dir().map(|d| d.join("github-status"))
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn max_age(&self) -> Duration {
+        self.max_age
+    }
+
+    fn path(&self, url: &str) -> PathBuf {
+        let name: String = url
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+
+        self.dir.join(format!("{name}.json"))
+    }
+
+    /// Loads the entry for `url`, treating an unreadable entry as missing.
+    pub(crate) fn load(&self, url: &str) -> Option<CacheEntry> {
+        let data = fs::read_to_string(self.path(url)).ok()?;
+        serde_json::from_str::<CacheEntry>(&data)
+            .ok()
+            .filter(|entry| entry.url == url)
+    }
+
+    pub(crate) fn store(&self, entry: &CacheEntry) -> Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        fs::write(self.path(&entry.url), serde_json::to_string(entry)?)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, time::Duration};
+
+    use chrono::{TimeDelta, Utc};
+
+    use super::{Cache, CacheEntry};
+
+    #[test]
+    fn test_store_and_load() {
+        let dir = env::temp_dir().join(format!("github-status-cache-{}", std::process::id()));
+        let cache = Cache::new(&dir, Duration::from_secs(60));
+        let entry = CacheEntry {
+            url: "https://www.githubstatus.com/api/v2/status.json".to_string(),
+            etag: Some("\"abc\"".to_string()),
+            last_modified: None,
+            fetched_at: Utc::now(),
+            body: "{}".to_string(),
+        };
+
+        assert_eq!(cache.load(&entry.url), None);
+        cache.store(&entry).unwrap();
+        assert_eq!(cache.load(&entry.url), Some(entry.clone()));
+        assert_eq!(
+            cache.load("https://www.githubstatus.com/api/v2/summary.json"),
+            None
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_is_fresh() {
+        let mut entry = CacheEntry {
+            url: "https://www.githubstatus.com/api/v2/status.json".to_string(),
+            etag: None,
+            last_modified: None,
+            fetched_at: Utc::now(),
+            body: "{}".to_string(),
+        };
+
+        assert!(entry.is_fresh(Duration::from_secs(60)));
+        assert!(!entry.is_fresh(Duration::ZERO));
+
+        entry.fetched_at -= TimeDelta::minutes(2);
+        assert!(!entry.is_fresh(Duration::from_secs(60)));
+    }
+}
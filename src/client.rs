@@ -0,0 +1,524 @@
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use reqwest::{
+    blocking::Client,
+    header::{ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED},
+    StatusCode,
+};
+use serde::de::DeserializeOwned;
+
+use crate::cache::{Cache, CacheEntry};
+use crate::error::{parse, FetchError};
+use crate::filter::ComponentFilter;
+use crate::http::{read_body, HttpOptions};
+use crate::models::{
+    ComponentInfo, Incident, IncidentInfo, MaintenanceInfo, StatusInfo, SummaryInfo,
+};
+
+pub const DEFAULT_BASE_URL: &str = "https://www.githubstatus.com";
+
+/// The Statuspage v2 endpoints.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Endpoint {
+    ActiveMaintenances,
+    Components,
+    Incidents,
+    ScheduledMaintenances,
+    Status,
+    Summary,
+    UnresolvedIncidents,
+    UpcomingMaintenances,
+}
+
+impl Endpoint {
+    pub fn path(&self) -> &'static str {
+        match self {
+            Endpoint::ActiveMaintenances => "api/v2/scheduled-maintenances/active.json",
+            Endpoint::Components => "api/v2/components.json",
+            Endpoint::Incidents => "api/v2/incidents.json",
+            Endpoint::ScheduledMaintenances => "api/v2/scheduled-maintenances.json",
+            Endpoint::Status => "api/v2/status.json",
+            Endpoint::Summary => "api/v2/summary.json",
+            Endpoint::UnresolvedIncidents => "api/v2/incidents/unresolved.json",
+            Endpoint::UpcomingMaintenances => "api/v2/scheduled-maintenances/upcoming.json",
+        }
+    }
+
+    /// Builds the full URL for the endpoint on the Statuspage found at `base_url`.
+    pub fn url(&self, base_url: &str) -> String {
+        format!("{}/{}", base_url.trim_end_matches('/'), self.path())
+    }
+}
+
+/// A client for the Statuspage v2 API, by default pointed at GitHub's status page.
+#[derive(Debug, Clone)]
+pub struct StatusClient {
+    base_url: String,
+    http: Client,
+    options: HttpOptions,
+    cache: Option<Cache>,
+    offline: bool,
+    input: Option<Input>,
+}
+
+/// A payload read ahead of time, used in place of every response.
+#[derive(Debug, Clone)]
+struct Input {
+    source: String,
+    body: String,
+}
+
+impl Default for StatusClient {
+    fn default() -> Self {
+        StatusClient::new(DEFAULT_BASE_URL)
+    }
+}
+
+impl StatusClient {
+    pub fn new(base_url: impl Into<String>) -> StatusClient {
+        StatusClient::with_options(base_url, HttpOptions::default())
+            .expect("the default HTTP client can be built")
+    }
+
+    /// Creates a client whose requests use the timeouts, retries, and certificates in `options`.
+    pub fn with_options(base_url: impl Into<String>, options: HttpOptions) -> Result<StatusClient> {
+        Ok(StatusClient {
+            base_url: base_url.into(),
+            http: options.build()?,
+            options,
+            cache: None,
+            offline: false,
+            input: None,
+        })
+    }
+
+    /// Caches responses on disk, revalidating them with the server once they are older than the
+    /// cache's max age.
+    pub fn with_cache(mut self, cache: Cache) -> StatusClient {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// If set, responses are only read from the cache and the network is never used.
+    pub fn offline(mut self, offline: bool) -> StatusClient {
+        self.offline = offline;
+        self
+    }
+
+    /// Deserializes every response from `body` instead of fetching it, e.g. to render an archived
+    /// payload. `source` names where the body came from in errors.
+    pub fn with_input(
+        mut self,
+        source: impl Into<String>,
+        body: impl Into<String>,
+    ) -> StatusClient {
+        self.input = Some(Input {
+            source: source.into(),
+            body: body.into(),
+        });
+        self
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// The underlying HTTP client, so other requests can share its settings.
+    pub fn http(&self) -> &Client {
+        &self.http
+    }
+
+    /// Fetches and deserializes the response from an endpoint. Failures are reported as a
+    /// [`FetchError`].
+    pub fn get<T: DeserializeOwned>(&self, endpoint: Endpoint) -> Result<T> {
+        if let Some(input) = &self.input {
+            return Ok(parse(&input.source, &input.body)?);
+        }
+
+        let url = endpoint.url(&self.base_url);
+        let body = self.fetch(&url)?;
+
+        Ok(parse(&url, &body)?)
+    }
+
+    /// Gets the response body for `url`, using the cache when possible.
+    fn fetch(&self, url: &str) -> Result<String, FetchError> {
+        let cached = self.cache.as_ref().and_then(|c| c.load(url));
+
+        if self.offline {
+            return cached.map(|e| e.body).ok_or_else(|| FetchError::NotCached {
+                url: url.to_string(),
+            });
+        }
+
+        if let (Some(cache), Some(entry)) = (&self.cache, &cached) {
+            if entry.is_fresh(cache.max_age()) {
+                return Ok(entry.body.clone());
+            }
+        }
+
+        let mut request = self.http.get(url);
+        if let Some(entry) = &cached {
+            if let Some(etag) = &entry.etag {
+                request = request.header(IF_NONE_MATCH, etag);
+            }
+            if let Some(last_modified) = &entry.last_modified {
+                request = request.header(IF_MODIFIED_SINCE, last_modified);
+            }
+        }
+
+        let sent_at = Instant::now();
+        let response = self
+            .options
+            .send(request)
+            .map_err(|e| FetchError::from_reqwest(url, e))?;
+
+        let entry = match cached {
+            Some(mut entry) if response.status() == StatusCode::NOT_MODIFIED => {
+                entry.fetched_at = Utc::now();
+                entry
+            }
+            _ => {
+                let response = response
+                    .error_for_status()
+                    .map_err(|e| FetchError::from_reqwest(url, e))?;
+                let header = |name| {
+                    response
+                        .headers()
+                        .get(name)
+                        .and_then(|v| v.to_str().ok())
+                        .map(String::from)
+                };
+
+                CacheEntry {
+                    url: url.to_string(),
+                    etag: header(ETAG),
+                    last_modified: header(LAST_MODIFIED),
+                    fetched_at: Utc::now(),
+                    body: read_body(response, sent_at, self.options.timeout)
+                        .map_err(|e| FetchError::from_io(url, e))?,
+                }
+            }
+        };
+
+        if let Some(cache) = &self.cache {
+            // A cache that can't be written to shouldn't stop the response from being used.
+            let _ = cache.store(&entry);
+        }
+
+        Ok(entry.body)
+    }
+
+    /// Gets the scheduled maintenances that are currently in progress.
+    pub fn active_maintenances(&self) -> Result<MaintenanceInfo> {
+        self.get(Endpoint::ActiveMaintenances)
+    }
+
+    /// Gets the status of each component.
+    pub fn components(&self) -> Result<ComponentInfo> {
+        self.get(Endpoint::Components)
+    }
+
+    /// Gets the 50 most recent incidents.
+    pub fn incidents(&self) -> Result<IncidentInfo> {
+        self.get(Endpoint::Incidents)
+    }
+
+    /// Finds an incident by its id or shortlink. Only the 50 most recent incidents are searched.
+    pub fn incident(&self, id_or_shortlink: &str) -> Result<Incident> {
+        self.incidents()?
+            .incidents
+            .into_iter()
+            .find(|i| i.matches(id_or_shortlink))
+            .ok_or_else(|| anyhow!("No recent incident found matching {id_or_shortlink:?}"))
+    }
+
+    /// Gets the 50 most recent scheduled maintenances.
+    pub fn scheduled_maintenances(&self) -> Result<MaintenanceInfo> {
+        self.get(Endpoint::ScheduledMaintenances)
+    }
+
+    /// Gets the overall status.
+    pub fn status(&self) -> Result<StatusInfo> {
+        self.get(Endpoint::Status)
+    }
+
+    /// Gets the overall status along with the status of each component.
+    pub fn summary(&self) -> Result<SummaryInfo> {
+        self.get(Endpoint::Summary)
+    }
+
+    /// Gets the incidents that have not been resolved.
+    pub fn unresolved_incidents(&self) -> Result<IncidentInfo> {
+        self.get(Endpoint::UnresolvedIncidents)
+    }
+
+    /// Gets the scheduled maintenances that have not started yet.
+    pub fn upcoming_maintenances(&self) -> Result<MaintenanceInfo> {
+        self.get(Endpoint::UpcomingMaintenances)
+    }
+
+    /// Polls the summary until the components selected by `filter` are operational, returning
+    /// `false` if `timeout` passes first. Failed polls are passed to `on_error`.
+    pub fn wait(
+        &self,
+        duration: Duration,
+        timeout: Option<Duration>,
+        filter: &ComponentFilter,
+        mut on_error: impl FnMut(&anyhow::Error),
+    ) -> Result<bool> {
+        // A timeout too long to represent never passes.
+        let deadline = timeout.and_then(|t| Instant::now().checked_add(t));
+
+        loop {
+            // Failed requests are retried on the next poll so a blip doesn't end the wait early.
+            match self.summary() {
+                Ok(s) if s.is_operational(filter)? => return Ok(true),
+                Ok(_) => {}
+                Err(e) => on_error(&e),
+            }
+
+            let sleep_for = match deadline {
+                Some(deadline) => {
+                    let remaining = deadline.saturating_duration_since(Instant::now());
+                    if remaining.is_zero() {
+                        return Ok(false);
+                    }
+                    duration.min(remaining)
+                }
+                None => duration,
+            };
+
+            thread::sleep(sleep_for);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+    use std::time::Duration;
+
+    use super::Endpoint;
+    use super::StatusClient;
+    use crate::cache::Cache;
+    use crate::error::FetchError;
+    use crate::filter::ComponentFilter;
+    use crate::fixtures::{empty_response, serve, serve_once, SUMMARY};
+    use crate::http::HttpOptions;
+    use crate::models::Indicator;
+
+    const STATUS_RESPONSE: &str = "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nETag: \"v1\"\r\nContent-Length: 95\r\nConnection: close\r\n\r\n{\"page\": {\"url\": \"http://localhost\"}, \"status\": {\"description\": \"Minor\", \"indicator\": \"minor\"}}";
+
+    fn temp_cache(name: &str, max_age: Duration) -> Cache {
+        let dir = env::temp_dir().join(format!("github-status-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        Cache::new(dir, max_age)
+    }
+
+    #[test]
+    fn test_cache_revalidates() {
+        let (base_url, rx) = serve(vec![STATUS_RESPONSE.to_string(), empty_response(304)]);
+        let cache = temp_cache("revalidate", Duration::ZERO);
+        let client = StatusClient::new(base_url).with_cache(cache.clone());
+
+        assert_eq!(client.status().unwrap().status.indicator, Indicator::Minor);
+        assert!(!rx.recv().unwrap().head.contains("if-none-match"));
+
+        assert_eq!(client.status().unwrap().status.indicator, Indicator::Minor);
+        assert!(rx.recv().unwrap().head.contains("if-none-match: \"v1\""));
+
+        fs::remove_dir_all(cache.dir()).unwrap();
+    }
+
+    #[test]
+    fn test_cache_fresh() {
+        let (base_url, rx) = serve(vec![STATUS_RESPONSE.to_string()]);
+        let cache = temp_cache("fresh", Duration::from_secs(60));
+        let client = StatusClient::new(base_url).with_cache(cache.clone());
+
+        client.status().unwrap();
+        rx.recv().unwrap();
+
+        // The server only responds once so this has to come from the cache.
+        assert_eq!(client.status().unwrap().status.indicator, Indicator::Minor);
+        assert!(rx.try_recv().is_err());
+
+        fs::remove_dir_all(cache.dir()).unwrap();
+    }
+
+    #[test]
+    fn test_offline() {
+        let (base_url, _rx) = serve(vec![STATUS_RESPONSE.to_string()]);
+        let cache = temp_cache("offline", Duration::ZERO);
+        StatusClient::new(&base_url)
+            .with_cache(cache.clone())
+            .status()
+            .unwrap();
+
+        let client = StatusClient::new(&base_url)
+            .with_cache(cache.clone())
+            .offline(true);
+        assert_eq!(client.status().unwrap().status.indicator, Indicator::Minor);
+        assert!(client.summary().is_err());
+
+        fs::remove_dir_all(cache.dir()).unwrap();
+    }
+
+    fn retrying_client(base_url: String, retries: u32) -> StatusClient {
+        let options = HttpOptions {
+            retries,
+            retry_delay: Duration::from_millis(1),
+            ..HttpOptions::default()
+        };
+
+        StatusClient::with_options(base_url, options).unwrap()
+    }
+
+    #[test]
+    fn test_retries_server_errors() {
+        let (base_url, rx) = serve(vec![empty_response(503), STATUS_RESPONSE.to_string()]);
+        let client = retrying_client(base_url, 1);
+
+        assert_eq!(client.status().unwrap().status.indicator, Indicator::Minor);
+        assert!(rx
+            .recv()
+            .unwrap()
+            .head
+            .contains("user-agent: github-status/"));
+        assert_eq!(rx.iter().count(), 1);
+    }
+
+    #[test]
+    fn test_retries_give_up() {
+        let (base_url, rx) = serve(vec![empty_response(503), empty_response(503)]);
+        let client = retrying_client(base_url, 1);
+
+        let err = client.status().unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<FetchError>(),
+            Some(FetchError::Status { status, .. }) if status.as_u16() == 503
+        ));
+        assert_eq!(rx.iter().count(), 2);
+    }
+
+    /// Serves the status response to a single request, sending the body in a few parts spread
+    /// over about half a second.
+    fn serve_slowly() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut buf = [0; 4096];
+            let _ = stream.read(&mut buf).unwrap();
+            let (head, body) =
+                STATUS_RESPONSE.split_at(STATUS_RESPONSE.find("\r\n\r\n").unwrap() + 4);
+            stream.write_all(head.as_bytes()).unwrap();
+            for part in body.as_bytes().chunks(25) {
+                thread::sleep(Duration::from_millis(150));
+                // The client may have given up already.
+                let _ = stream.write_all(part);
+            }
+        });
+
+        format!("http://{addr}")
+    }
+
+    #[test]
+    fn test_read_timeout() {
+        let options = HttpOptions {
+            read_timeout: Duration::from_millis(400),
+            ..HttpOptions::default()
+        };
+
+        // The response takes longer than the read timeout, but keeps arriving.
+        let client = StatusClient::with_options(serve_slowly(), options.clone()).unwrap();
+        assert_eq!(client.status().unwrap().status.indicator, Indicator::Minor);
+
+        let options = HttpOptions {
+            timeout: Some(Duration::from_millis(200)),
+            ..options
+        };
+        let client = StatusClient::with_options(serve_slowly(), options).unwrap();
+        let err = client.status().unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<FetchError>(),
+            Some(FetchError::Timeout { .. })
+        ));
+    }
+
+    #[test]
+    fn test_input() {
+        let client = StatusClient::new("http://127.0.0.1:1").with_input("summary.json", SUMMARY);
+
+        assert_eq!(client.summary().unwrap().status.indicator, Indicator::Minor);
+
+        let err = client.scheduled_maintenances().unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<FetchError>(),
+            Some(FetchError::Schema { url, .. }) if url == "summary.json"
+        ));
+    }
+
+    #[test]
+    fn test_endpoint_url() {
+        assert_eq!(
+            Endpoint::Summary.url("https://www.githubstatus.com"),
+            "https://www.githubstatus.com/api/v2/summary.json"
+        );
+        assert_eq!(
+            Endpoint::UnresolvedIncidents.url("https://status.npmjs.org/"),
+            "https://status.npmjs.org/api/v2/incidents/unresolved.json"
+        );
+    }
+
+    #[test]
+    fn test_status_from_base_url() {
+        let base_url = serve_once(
+            r#"{
+              "page": {"url": "http://localhost", "updated_at": null},
+              "status": {"description": "All Systems Operational", "indicator": "none"}
+            }"#,
+        );
+
+        let info = StatusClient::new(base_url).status().unwrap();
+        assert_eq!(info.status.indicator, Indicator::None);
+    }
+
+    #[test]
+    fn test_wait_operational() {
+        let client = StatusClient::new(serve_once(SUMMARY));
+        let result = client.wait(
+            Duration::from_secs(60),
+            // Too long to add to the current time.
+            Some(Duration::MAX),
+            &ComponentFilter::new(["Git Operations"]),
+            |e| panic!("{e}"),
+        );
+
+        assert!(result.unwrap());
+    }
+
+    #[test]
+    fn test_wait_timeout() {
+        let client = StatusClient::new(serve_once(SUMMARY));
+        let result = client.wait(
+            Duration::from_secs(60),
+            Some(Duration::ZERO),
+            &ComponentFilter::new(["Actions"]),
+            |e| panic!("{e}"),
+        );
+
+        assert!(!result.unwrap());
+    }
+}
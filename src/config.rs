@@ -0,0 +1,646 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{anyhow, bail, Context, Result};
+use clap::{parser::ValueSource, ArgMatches};
+use serde::Deserialize;
+
+use crate::options::{parse_period, parse_tz, ColorChoice, Command, Options, OutputFormat, Report};
+use crate::webhook::WebhookFormat;
+
+/// Defaults for the command line options, read from the config file. Options passed on the
+/// command line or set with environment variables take precedence.
+#[derive(Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct Settings {
+    pub base_url: Option<String>,
+    pub format: Option<OutputFormat>,
+    pub color: Option<ColorChoice>,
+    pub tz: Option<String>,
+    pub relative: Option<bool>,
+    pub pager: Option<bool>,
+    pub components: Option<Vec<String>>,
+    pub providers: Option<PathBuf>,
+    pub history_dir: Option<PathBuf>,
+
+    #[serde(default)]
+    pub watch: WatchSettings,
+
+    #[serde(default)]
+    pub wait: WaitSettings,
+
+    #[serde(default)]
+    pub serve: ServeSettings,
+
+    #[serde(default)]
+    pub record: RecordSettings,
+
+    #[serde(default)]
+    pub report: ReportSettings,
+
+    /// Named sets of settings selected with `--profile`, which override the settings above.
+    #[serde(default)]
+    pub profiles: BTreeMap<String, Settings>,
+}
+
+/// Defaults for the `watch` command.
+#[derive(Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct WatchSettings {
+    pub duration: Option<u64>,
+    pub cancel_when_operational: Option<bool>,
+    pub changes: Option<bool>,
+    pub record: Option<bool>,
+    pub on_change: Option<String>,
+    pub on_degraded: Option<String>,
+    pub on_recovered: Option<String>,
+    pub webhook: Option<String>,
+    pub webhook_format: Option<WebhookFormat>,
+    pub webhook_retries: Option<u32>,
+}
+
+impl WatchSettings {
+    /// Combines the settings, preferring the values in `other`.
+    fn merge(self, other: WatchSettings) -> WatchSettings {
+        WatchSettings {
+            duration: other.duration.or(self.duration),
+            cancel_when_operational: other
+                .cancel_when_operational
+                .or(self.cancel_when_operational),
+            changes: other.changes.or(self.changes),
+            record: other.record.or(self.record),
+            on_change: other.on_change.or(self.on_change),
+            on_degraded: other.on_degraded.or(self.on_degraded),
+            on_recovered: other.on_recovered.or(self.on_recovered),
+            webhook: other.webhook.or(self.webhook),
+            webhook_format: other.webhook_format.or(self.webhook_format),
+            webhook_retries: other.webhook_retries.or(self.webhook_retries),
+        }
+    }
+}
+
+/// Defaults for the `wait` command.
+#[derive(Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct WaitSettings {
+    pub duration: Option<u64>,
+    pub timeout: Option<u64>,
+}
+
+impl WaitSettings {
+    /// Combines the settings, preferring the values in `other`.
+    fn merge(self, other: WaitSettings) -> WaitSettings {
+        WaitSettings {
+            duration: other.duration.or(self.duration),
+            timeout: other.timeout.or(self.timeout),
+        }
+    }
+}
+
+/// Defaults for the `serve` command.
+#[derive(Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct ServeSettings {
+    pub listen: Option<String>,
+    pub duration: Option<u64>,
+}
+
+impl ServeSettings {
+    /// Combines the settings, preferring the values in `other`.
+    fn merge(self, other: ServeSettings) -> ServeSettings {
+        ServeSettings {
+            listen: other.listen.or(self.listen),
+            duration: other.duration.or(self.duration),
+        }
+    }
+}
+
+/// Defaults for the `record` command.
+#[derive(Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct RecordSettings {
+    pub duration: Option<u64>,
+}
+
+impl RecordSettings {
+    /// Combines the settings, preferring the values in `other`.
+    fn merge(self, other: RecordSettings) -> RecordSettings {
+        RecordSettings {
+            duration: other.duration.or(self.duration),
+        }
+    }
+}
+
+/// Defaults for the `report` commands.
+#[derive(Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct ReportSettings {
+    #[serde(default)]
+    pub uptime: UptimeSettings,
+}
+
+/// Defaults for the `report uptime` command.
+#[derive(Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct UptimeSettings {
+    /// The period to report on, e.g. `90d`.
+    pub since: Option<String>,
+}
+
+impl ReportSettings {
+    /// Combines the settings, preferring the values in `other`.
+    fn merge(self, other: ReportSettings) -> ReportSettings {
+        ReportSettings {
+            uptime: UptimeSettings {
+                since: other.uptime.since.or(self.uptime.since),
+            },
+        }
+    }
+}
+
+impl Settings {
+    pub fn parse(data: &str) -> Result<Settings> {
+        let settings: Settings = toml::from_str(data)?;
+        if let Some((name, _)) = settings
+            .profiles
+            .iter()
+            .find(|(_, p)| !p.profiles.is_empty())
+        {
+            bail!("Profile {name:?} can't contain other profiles");
+        }
+
+        Ok(settings)
+    }
+
+    /// The config file in the user's config dir, e.g. `~/.config/github-status/config.toml`.
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|d| d.join("github-status").join("config.toml"))
+    }
+
+    /// Loads the config file at `path`, or the default one if it exists, and applies `profile`.
+    pub fn load(path: Option<&Path>, profile: Option<&str>) -> Result<Settings> {
+        let settings = match path {
+            Some(path) => Some(read(path)?),
+            None => match Settings::default_path().filter(|p| p.exists()) {
+                Some(path) => Some(read(&path)?),
+                None => None,
+            },
+        };
+
+        match (settings, profile) {
+            (Some(settings), Some(profile)) => settings.with_profile(profile),
+            (Some(settings), None) => Ok(settings),
+            (None, Some(profile)) => bail!("No config file found for profile {profile:?}"),
+            (None, None) => Ok(Settings::default()),
+        }
+    }
+
+    /// The settings with the values from the profile `name` taking precedence.
+    pub fn with_profile(mut self, name: &str) -> Result<Settings> {
+        let profile = self
+            .profiles
+            .remove(name)
+            .ok_or_else(|| anyhow!("No profile named {name:?} in the config file"))?;
+
+        Ok(Settings {
+            base_url: profile.base_url.or(self.base_url),
+            format: profile.format.or(self.format),
+            color: profile.color.or(self.color),
+            tz: profile.tz.or(self.tz),
+            relative: profile.relative.or(self.relative),
+            pager: profile.pager.or(self.pager),
+            components: profile.components.or(self.components),
+            providers: profile.providers.or(self.providers),
+            history_dir: profile.history_dir.or(self.history_dir),
+            watch: self.watch.merge(profile.watch),
+            wait: self.wait.merge(profile.wait),
+            serve: self.serve.merge(profile.serve),
+            record: self.record.merge(profile.record),
+            report: self.report.merge(profile.report),
+            profiles: BTreeMap::new(),
+        })
+    }
+
+    /// Fills in the options that weren't passed on the command line or set with environment
+    /// variables.
+    pub fn apply(self, opt: &mut Options, matches: &ArgMatches) -> Result<()> {
+        if self.components.is_some() && self.providers.is_some() {
+            bail!("The config can't set both components and providers");
+        }
+
+        // Global options are propagated down to the innermost subcommand's matches, e.g. those of
+        // `report uptime`.
+        let mut matches = matches;
+        while let Some((_, subcommand)) = matches.subcommand() {
+            matches = subcommand;
+        }
+
+        macro_rules! apply {
+            ($id:literal, $field:expr, $value:expr) => {
+                if let Some(value) = $value {
+                    if is_unset(matches, $id) {
+                        $field = value;
+                    }
+                }
+            };
+        }
+
+        apply!("base_url", opt.base_url, self.base_url);
+        apply!("format", opt.format, self.format);
+        apply!("color", opt.color, self.color);
+        apply!("relative", opt.relative, self.relative);
+        apply!("history_dir", opt.history_dir, self.history_dir.map(Some));
+        if let Some(tz) = &self.tz {
+            if is_unset(matches, "tz") {
+                opt.tz = Some(parse_tz(tz).map_err(|e| anyhow!("Invalid tz in the config: {e}"))?);
+            }
+        }
+
+        match &mut opt.command {
+            Command::ActiveMaintenance { pager }
+            | Command::AllIncidents { pager }
+            | Command::AllScheduledMaintenances { pager }
+            | Command::Incident { pager, .. }
+            | Command::Status { pager }
+            | Command::UnresolvedIncidents { pager }
+            | Command::UpcomingMaintenance { pager } => {
+                apply!("pager", *pager, self.pager);
+            }
+            Command::Component { pager, components }
+            | Command::History {
+                pager, components, ..
+            } => {
+                apply!("pager", *pager, self.pager);
+                apply!("components", *components, self.components);
+            }
+            Command::Summary {
+                pager,
+                components,
+                providers,
+            } => {
+                apply!("pager", *pager, self.pager);
+                apply_filter(
+                    matches,
+                    components,
+                    providers,
+                    self.components,
+                    self.providers,
+                );
+            }
+            Command::Dashboard { components, .. } => {
+                apply!("components", *components, self.components);
+            }
+            Command::Wait {
+                duration,
+                timeout,
+                components,
+            } => {
+                apply!("duration", *duration, self.wait.duration);
+                apply!("timeout", *timeout, self.wait.timeout.map(Some));
+                apply!("components", *components, self.components);
+            }
+            Command::Watch {
+                duration,
+                cancel_when_operational,
+                changes,
+                record,
+                on_change,
+                on_degraded,
+                on_recovered,
+                webhook,
+                webhook_format,
+                webhook_retries,
+                components,
+                providers,
+            } => {
+                let watch = self.watch;
+                apply!("duration", *duration, watch.duration);
+                apply!(
+                    "cancel_when_operational",
+                    *cancel_when_operational,
+                    watch.cancel_when_operational
+                );
+                apply!("changes", *changes, watch.changes);
+                apply!("record", *record, watch.record);
+                apply!("on_change", *on_change, watch.on_change.map(Some));
+                apply!("on_degraded", *on_degraded, watch.on_degraded.map(Some));
+                apply!("on_recovered", *on_recovered, watch.on_recovered.map(Some));
+                apply!("webhook", *webhook, watch.webhook.map(Some));
+                apply!("webhook_format", *webhook_format, watch.webhook_format);
+                apply!("webhook_retries", *webhook_retries, watch.webhook_retries);
+                apply_filter(
+                    matches,
+                    components,
+                    providers,
+                    self.components,
+                    self.providers,
+                );
+            }
+            Command::Report {
+                report:
+                    Report::Uptime {
+                        since,
+                        pager,
+                        components,
+                    },
+            } => {
+                if let Some(value) = &self.report.uptime.since {
+                    if is_unset(matches, "since") {
+                        *since = parse_period(value).map_err(|e| {
+                            anyhow!("Invalid report.uptime.since in the config: {e}")
+                        })?;
+                    }
+                }
+                apply!("pager", *pager, self.pager);
+                apply!("components", *components, self.components);
+            }
+            Command::Record {
+                duration,
+                providers,
+            } => {
+                apply!("duration", *duration, self.record.duration);
+                apply!("providers", *providers, self.providers.map(Some));
+            }
+            Command::Serve { listen, duration } => {
+                apply!("listen", *listen, self.serve.listen);
+                apply!("duration", *duration, self.serve.duration);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn read(path: &Path) -> Result<Settings> {
+    let data = fs::read_to_string(path)
+        .with_context(|| format!("Unable to read the config file {}", path.display()))?;
+
+    Settings::parse(&data).with_context(|| format!("Invalid config file {}", path.display()))
+}
+
+/// Returns `true` if the option wasn't passed on the command line or set with an environment
+/// variable.
+fn is_unset(matches: &ArgMatches, id: &str) -> bool {
+    !matches!(
+        matches.value_source(id),
+        Some(ValueSource::CommandLine | ValueSource::EnvVariable)
+    )
+}
+
+/// Applies the configured components or providers, unless either was passed on the command line
+/// since they can't be used together.
+fn apply_filter(
+    matches: &ArgMatches,
+    components: &mut Vec<String>,
+    providers: &mut Option<PathBuf>,
+    configured_components: Option<Vec<String>>,
+    configured_providers: Option<PathBuf>,
+) {
+    if !is_unset(matches, "components") || !is_unset(matches, "providers") {
+        return;
+    }
+
+    if let Some(path) = configured_providers {
+        *providers = Some(path);
+    }
+    if let Some(configured) = configured_components {
+        *components = configured;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeDelta;
+    use clap::{CommandFactory, FromArgMatches};
+
+    use super::Settings;
+    use crate::options::{Command, Options, OutputFormat, Report};
+    use crate::webhook::WebhookFormat;
+
+    const CONFIG: &str = r#"
+        format = "table"
+        tz = "UTC"
+        pager = true
+        relative = true
+        components = ["Actions"]
+
+        [watch]
+        duration = 5
+        changes = true
+        webhook = "https://example.com/hook"
+
+        [wait]
+        timeout = 60
+
+        [serve]
+        listen = "0.0.0.0:9617"
+
+        [record]
+        duration = 2
+
+        [report.uptime]
+        since = "90d"
+
+        [profiles.ci]
+        format = "json"
+
+        [profiles.ci.watch]
+        webhook_format = "slack"
+    "#;
+
+    fn options(settings: Settings, args: &[&str]) -> Options {
+        let matches = Options::command()
+            .try_get_matches_from(["github-status"].iter().chain(args))
+            .unwrap();
+        let mut opt = Options::from_arg_matches(&matches).unwrap();
+        settings.apply(&mut opt, &matches).unwrap();
+        opt
+    }
+
+    #[test]
+    fn test_apply() {
+        let settings = Settings::parse(CONFIG).unwrap();
+
+        let opt = options(settings.clone(), &["watch"]);
+        assert_eq!(opt.format, OutputFormat::Table);
+        assert_eq!(opt.tz, Some(chrono_tz::UTC));
+        let Command::Watch {
+            duration,
+            webhook,
+            webhook_format,
+            components,
+            ..
+        } = opt.command
+        else {
+            panic!("expected the watch command");
+        };
+        assert_eq!(duration, 5);
+        assert_eq!(webhook.as_deref(), Some("https://example.com/hook"));
+        assert_eq!(webhook_format, WebhookFormat::Generic);
+        assert_eq!(components, vec!["Actions"]);
+
+        let opt = options(settings.clone(), &["report", "uptime"]);
+        let Command::Report {
+            report:
+                Report::Uptime {
+                    since,
+                    pager,
+                    components,
+                },
+        } = opt.command
+        else {
+            panic!("expected the report uptime command");
+        };
+        assert_eq!(since, TimeDelta::days(90));
+        assert!(pager);
+        assert_eq!(components, vec!["Actions"]);
+
+        let opt = options(settings.clone(), &["wait"]);
+        let Command::Wait {
+            duration,
+            timeout,
+            components,
+        } = opt.command
+        else {
+            panic!("expected the wait command");
+        };
+        assert_eq!((duration, timeout), (1, Some(60)));
+        assert_eq!(components, vec!["Actions"]);
+
+        let opt = options(settings.clone(), &["serve"]);
+        let Command::Serve { listen, duration } = opt.command else {
+            panic!("expected the serve command");
+        };
+        assert_eq!((listen.as_str(), duration), ("0.0.0.0:9617", 1));
+
+        let opt = options(settings, &["record"]);
+        let Command::Record { duration, .. } = opt.command else {
+            panic!("expected the record command");
+        };
+        assert_eq!(duration, 2);
+    }
+
+    #[test]
+    fn test_command_line_overrides() {
+        let settings = Settings::parse(CONFIG).unwrap();
+
+        let opt = options(
+            settings,
+            &[
+                "--format",
+                "text",
+                "watch",
+                "-d",
+                "2",
+                "--component",
+                "Pages",
+            ],
+        );
+        assert_eq!(opt.format, OutputFormat::Text);
+        let Command::Watch {
+            duration,
+            components,
+            ..
+        } = opt.command
+        else {
+            panic!("expected the watch command");
+        };
+        assert_eq!(duration, 2);
+        assert_eq!(components, vec!["Pages"]);
+
+        let settings = Settings::parse(CONFIG).unwrap();
+        let opt = options(settings, &["summary", "--format", "text"]);
+        assert_eq!(opt.format, OutputFormat::Text);
+
+        let settings = Settings::parse(CONFIG).unwrap();
+        let opt = options(
+            settings,
+            &[
+                "report",
+                "uptime",
+                "--component",
+                "Pages",
+                "--format",
+                "text",
+            ],
+        );
+        assert_eq!(opt.format, OutputFormat::Text);
+        let Command::Report {
+            report: Report::Uptime { components, .. },
+        } = opt.command
+        else {
+            panic!("expected the report uptime command");
+        };
+        assert_eq!(components, vec!["Pages"]);
+
+        let settings = Settings::parse(CONFIG).unwrap();
+        let opt = options(settings, &["report", "uptime", "--since", "1w"]);
+        let Command::Report {
+            report: Report::Uptime { since, .. },
+        } = opt.command
+        else {
+            panic!("expected the report uptime command");
+        };
+        assert_eq!(since, TimeDelta::weeks(1));
+
+        // Switches the config turns on can be turned off again.
+        let settings = Settings::parse(CONFIG).unwrap();
+        let opt = options(settings, &["--relative=false", "watch", "--changes=false"]);
+        assert!(!opt.relative);
+        let Command::Watch { changes, .. } = opt.command else {
+            panic!("expected the watch command");
+        };
+        assert!(!changes);
+
+        let settings = Settings::parse(CONFIG).unwrap();
+        let opt = options(settings.clone(), &["incident", "--pager=false", "abc"]);
+        let Command::Incident { pager, .. } = opt.command else {
+            panic!("expected the incident command");
+        };
+        assert!(!pager);
+
+        // Without a value the switch is turned on, and the next argument isn't taken as its value.
+        let opt = options(settings, &["incident", "--pager", "abc"]);
+        let Command::Incident { id, pager } = opt.command else {
+            panic!("expected the incident command");
+        };
+        assert_eq!((id.as_str(), pager), ("abc", true));
+    }
+
+    #[test]
+    fn test_profile() {
+        let settings = Settings::parse(CONFIG).unwrap().with_profile("ci").unwrap();
+
+        assert_eq!(settings.format, Some(OutputFormat::Json));
+        assert_eq!(settings.tz.as_deref(), Some("UTC"));
+        assert_eq!(settings.watch.duration, Some(5));
+        assert_eq!(settings.watch.webhook_format, Some(WebhookFormat::Slack));
+        assert_eq!(settings.report.uptime.since.as_deref(), Some("90d"));
+
+        assert!(Settings::parse(CONFIG)
+            .unwrap()
+            .with_profile("missing")
+            .is_err());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Settings::parse("formatt = \"json\"").is_err());
+        assert!(Settings::parse("format = \"yaml\"").is_err());
+        assert!(Settings::parse("[profiles.a.profiles.b]").is_err());
+        assert!(Settings::parse("[report]\nsince = \"90d\"").is_err());
+
+        let settings = Settings::parse("components = [\"Actions\"]\nproviders = \"p.toml\"");
+        let matches = Options::command().get_matches_from(["github-status", "summary"]);
+        let mut opt = Options::from_arg_matches(&matches).unwrap();
+        assert!(settings.unwrap().apply(&mut opt, &matches).is_err());
+
+        let settings = Settings::parse("[report.uptime]\nsince = \"soon\"");
+        let matches = Options::command().get_matches_from(["github-status", "report", "uptime"]);
+        let mut opt = Options::from_arg_matches(&matches).unwrap();
+        assert!(settings.unwrap().apply(&mut opt, &matches).is_err());
+    }
+}
@@ -0,0 +1,523 @@
+use std::{
+    io::{self, IsTerminal},
+    sync::mpsc::{self, RecvTimeoutError},
+    thread,
+    time::Duration,
+};
+
+use anyhow::{bail, Result};
+use chrono::{DateTime, Utc};
+use github_status::{Component, ComponentFilter, Incident, StatusClient, SummaryInfo};
+use ratatui::{
+    crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::output::{StatusColor, TimeFormat};
+
+/// How often key presses are checked for while waiting for the next refresh.
+const TICK: Duration = Duration::from_millis(250);
+
+/// What to do after a key press.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    None,
+    Refresh,
+    Quit,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum View {
+    Overview,
+
+    /// The timeline of the incident with the id, scrolled down by `scroll` lines.
+    Incident {
+        id: String,
+        scroll: u16,
+    },
+}
+
+/// The state of the dashboard between frames.
+#[derive(Debug)]
+pub struct App {
+    summary: Option<SummaryInfo>,
+    error: Option<String>,
+    updated_at: Option<DateTime<Utc>>,
+    filter: ComponentFilter,
+    time: TimeFormat,
+    incidents: ListState,
+    view: View,
+}
+
+impl App {
+    pub fn new(filter: ComponentFilter, time: TimeFormat) -> App {
+        App {
+            summary: None,
+            error: None,
+            updated_at: None,
+            filter,
+            time,
+            incidents: ListState::default(),
+            view: View::Overview,
+        }
+    }
+
+    /// Shows the result of a refresh. If it failed the previous summary is kept along with the
+    /// error.
+    pub fn update(&mut self, result: Result<SummaryInfo>, at: DateTime<Utc>) {
+        let result = result.and_then(|mut summary| {
+            summary.retain(&self.filter)?;
+            Ok(summary)
+        });
+
+        match result {
+            Ok(summary) => {
+                let count = summary.incidents.len();
+                self.incidents.select(match self.incidents.selected() {
+                    _ if count == 0 => None,
+                    Some(i) => Some(i.min(count - 1)),
+                    None => Some(0),
+                });
+                if let View::Incident { id, .. } = &self.view {
+                    if !summary.incidents.iter().any(|i| &i.id == id) {
+                        self.view = View::Overview;
+                    }
+                }
+
+                self.summary = Some(summary);
+                self.error = None;
+                self.updated_at = Some(at);
+            }
+            Err(e) => self.error = Some(e.to_string()),
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> Action {
+        // Raw mode stops Ctrl + c from interrupting the process.
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            return Action::Quit;
+        }
+
+        match (&mut self.view, key.code) {
+            (_, KeyCode::Char('q')) => return Action::Quit,
+            (_, KeyCode::Char('r')) => return Action::Refresh,
+            (View::Overview, KeyCode::Esc) => return Action::Quit,
+            (View::Overview, KeyCode::Up | KeyCode::Char('k')) => self.incidents.select_previous(),
+            (View::Overview, KeyCode::Down | KeyCode::Char('j')) => {
+                let count = self.summary.as_ref().map_or(0, |s| s.incidents.len());
+                if self.incidents.selected().is_some_and(|i| i + 1 < count) {
+                    self.incidents.select_next();
+                }
+            }
+            (View::Overview, KeyCode::Enter) => {
+                if let Some(incident) = self.selected_incident() {
+                    self.view = View::Incident {
+                        id: incident.id.clone(),
+                        scroll: 0,
+                    };
+                }
+            }
+            (View::Incident { .. }, KeyCode::Esc | KeyCode::Backspace | KeyCode::Left) => {
+                self.view = View::Overview;
+            }
+            (View::Incident { scroll, .. }, KeyCode::Up | KeyCode::Char('k')) => {
+                *scroll = scroll.saturating_sub(1);
+            }
+            (View::Incident { scroll, .. }, KeyCode::Down | KeyCode::Char('j')) => {
+                *scroll = scroll.saturating_add(1);
+            }
+            (View::Incident { scroll, .. }, KeyCode::PageUp) => *scroll = scroll.saturating_sub(10),
+            (View::Incident { scroll, .. }, KeyCode::PageDown) => {
+                *scroll = scroll.saturating_add(10);
+            }
+            _ => {}
+        }
+
+        Action::None
+    }
+
+    fn selected_incident(&self) -> Option<&Incident> {
+        self.summary
+            .as_ref()?
+            .incidents
+            .get(self.incidents.selected()?)
+    }
+
+    pub fn render(&mut self, frame: &mut Frame) {
+        let [header, body, footer] = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Min(0),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+
+        self.render_header(frame, header);
+
+        let help = match &self.view {
+            View::Overview => {
+                self.render_overview(frame, body);
+                "↑/↓ select incident  Enter open  r refresh  q quit"
+            }
+            View::Incident { .. } => {
+                self.render_incident(frame, body);
+                "↑/↓ scroll  Esc back  r refresh  q quit"
+            }
+        };
+        frame.render_widget(
+            Paragraph::new(help).style(Style::new().fg(Color::DarkGray)),
+            footer,
+        );
+    }
+
+    fn render_header(&self, frame: &mut Frame, area: Rect) {
+        let mut lines = Vec::new();
+        match &self.summary {
+            Some(summary) => lines.push(Line::from(vec![
+                Span::styled(
+                    summary.status.description.clone(),
+                    Style::new()
+                        .fg(tui_color(summary.status.indicator.color()))
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::raw(match self.updated_at {
+                    Some(at) => format!("  Updated {}", self.time.format(&at.fixed_offset())),
+                    None => String::new(),
+                }),
+            ])),
+            None => lines.push(Line::from("Loading...")),
+        }
+        if let Some(error) = &self.error {
+            lines.push(Line::styled(
+                format!("Error: {error}"),
+                Style::new().fg(Color::Red),
+            ));
+        }
+
+        frame.render_widget(Paragraph::new(lines), area);
+    }
+
+    fn render_overview(&mut self, frame: &mut Frame, area: Rect) {
+        let Some(summary) = &self.summary else {
+            return;
+        };
+
+        let components: Vec<&Component> = summary
+            .components
+            .iter()
+            .filter(|c| c.showcase && !c.group)
+            .collect();
+        let width = components
+            .iter()
+            .map(|c| c.name.chars().count() + 4)
+            .max()
+            .unwrap_or(0);
+        let columns = (usize::from(area.width.saturating_sub(2)) / width.max(1)).max(1);
+        let rows = components.len().div_ceil(columns);
+
+        let [grid, incidents, maintenances] = Layout::vertical([
+            Constraint::Length(rows as u16 + 2),
+            Constraint::Min(3),
+            Constraint::Length(summary.scheduled_maintenances.len().max(1) as u16 + 2),
+        ])
+        .areas(area);
+
+        let lines: Vec<Line> = components
+            .chunks(columns)
+            .map(|row| {
+                Line::from(
+                    row.iter()
+                        .map(|c| {
+                            Span::styled(
+                                format!("● {:width$}", c.name, width = width - 2),
+                                Style::new().fg(tui_color(c.status.color())),
+                            )
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect();
+        frame.render_widget(
+            Paragraph::new(lines).block(Block::bordered().title("Components")),
+            grid,
+        );
+
+        let items: Vec<ListItem> = summary
+            .incidents
+            .iter()
+            .map(|incident| {
+                ListItem::new(Line::from(vec![
+                    Span::styled(
+                        incident.name.clone(),
+                        Style::new().fg(tui_color(incident.impact.color())),
+                    ),
+                    Span::raw(format!(" ({})", incident.status)),
+                ]))
+            })
+            .collect();
+        let block = Block::bordered().title("Unresolved Incidents");
+        if items.is_empty() {
+            frame.render_widget(
+                Paragraph::new("No unresolved incidents").block(block),
+                incidents,
+            );
+        } else {
+            let list = List::new(items)
+                .block(block)
+                .highlight_symbol("> ")
+                .highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+            frame.render_stateful_widget(list, incidents, &mut self.incidents);
+        }
+
+        let lines: Vec<Line> = if summary.scheduled_maintenances.is_empty() {
+            vec![Line::from("No upcoming maintenance")]
+        } else {
+            summary
+                .scheduled_maintenances
+                .iter()
+                .map(|m| Line::from(format!("{} ({})", m.name, m.status)))
+                .collect()
+        };
+        frame.render_widget(
+            Paragraph::new(lines).block(Block::bordered().title("Scheduled Maintenance")),
+            maintenances,
+        );
+    }
+
+    fn render_incident(&self, frame: &mut Frame, area: Rect) {
+        let View::Incident { id, scroll } = &self.view else {
+            return;
+        };
+        let Some(incident) = self
+            .summary
+            .as_ref()
+            .and_then(|s| s.incidents.iter().find(|i| &i.id == id))
+        else {
+            return;
+        };
+
+        let bold = Style::new().add_modifier(Modifier::BOLD);
+        let mut lines = vec![
+            Line::from(format!("Impact: {}", incident.impact)),
+            Line::from(format!("Status: {}", incident.status)),
+            Line::from(format!("Short Link: {}", incident.shortlink)),
+        ];
+        for update in incident.timeline().into_iter().rev() {
+            lines.push(Line::default());
+            let time = update
+                .created_at
+                .as_ref()
+                .map(|t| format!("{}: ", self.time.format(t)))
+                .unwrap_or_default();
+            lines.push(Line::styled(format!("{time}{}", update.status), bold));
+            lines.push(Line::from(update.body.clone()));
+            for component in update.affected_components.iter().flatten() {
+                lines.push(Line::from(vec![
+                    Span::raw(format!("{}: ", component.name)),
+                    Span::styled(
+                        component.new_status.label().to_string(),
+                        Style::new().fg(tui_color(component.new_status.color())),
+                    ),
+                ]));
+            }
+        }
+
+        let scroll = (*scroll).min(lines.len().saturating_sub(1) as u16);
+        let title = Span::styled(
+            incident.name.clone(),
+            bold.fg(tui_color(incident.impact.color())),
+        );
+        frame.render_widget(
+            Paragraph::new(lines)
+                .block(Block::bordered().title(title))
+                .wrap(Wrap { trim: false })
+                .scroll((scroll, 0)),
+            area,
+        );
+    }
+}
+
+/// Converts a status color to the terminal UI's colors.
+fn tui_color(color: Option<colored::Color>) -> Color {
+    match color {
+        Some(colored::Color::Green) => Color::Green,
+        Some(colored::Color::Yellow) => Color::Yellow,
+        Some(colored::Color::Red) => Color::Red,
+        Some(colored::Color::Blue) => Color::Blue,
+        Some(colored::Color::TrueColor { r, g, b }) => Color::Rgb(r, g, b),
+        _ => Color::Reset,
+    }
+}
+
+/// Shows the dashboard until it's quit, refreshing the summary every `duration` in the
+/// background so the interface stays responsive.
+pub fn run(
+    client: StatusClient,
+    filter: ComponentFilter,
+    duration: Duration,
+    time: TimeFormat,
+) -> Result<()> {
+    if !io::stdout().is_terminal() {
+        bail!("The dashboard can only be shown in a terminal");
+    }
+
+    let (results_tx, results) = mpsc::channel();
+    let (refresh, refresh_rx) = mpsc::channel();
+    thread::spawn(move || loop {
+        if results_tx.send(client.summary()).is_err() {
+            return;
+        }
+        match refresh_rx.recv_timeout(duration) {
+            Ok(()) | Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => return,
+        }
+    });
+
+    let mut app = App::new(filter, time);
+    let mut terminal = ratatui::try_init()?;
+    let result = (|| -> Result<()> {
+        loop {
+            while let Ok(result) = results.try_recv() {
+                app.update(result, Utc::now());
+            }
+            terminal.draw(|frame| app.render(frame))?;
+
+            if !event::poll(TICK)? {
+                continue;
+            }
+            if let Event::Key(key) = event::read()? {
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+                match app.handle_key(key) {
+                    Action::Quit => return Ok(()),
+                    Action::Refresh => {
+                        let _ = refresh.send(());
+                    }
+                    Action::None => {}
+                }
+            }
+        }
+    })();
+    ratatui::restore();
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+    use chrono::{DateTime, Utc};
+    use github_status::{ComponentFilter, SummaryInfo};
+    use ratatui::{
+        backend::TestBackend,
+        crossterm::event::{KeyCode, KeyEvent, KeyModifiers},
+        Terminal,
+    };
+
+    use super::{Action, App};
+    use crate::fixtures::SUMMARY;
+    use crate::output::TimeFormat;
+
+    fn app() -> App {
+        filtered_app(ComponentFilter::default())
+    }
+
+    fn filtered_app(filter: ComponentFilter) -> App {
+        let mut app = App::new(
+            filter,
+            TimeFormat {
+                tz: Some(chrono_tz::UTC),
+                relative: false,
+            },
+        );
+        app.update(
+            Ok(serde_json::from_str::<SummaryInfo>(SUMMARY).unwrap()),
+            "2024-01-10T10:30:00Z".parse::<DateTime<Utc>>().unwrap(),
+        );
+        app
+    }
+
+    fn screen(app: &mut App) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+        terminal.draw(|frame| app.render(frame)).unwrap();
+
+        let buffer = terminal.backend().buffer();
+        (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer[(x, y)].symbol())
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn press(app: &mut App, code: KeyCode) -> Action {
+        app.handle_key(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    #[test]
+    fn test_overview() {
+        let mut app = app();
+        let screen = screen(&mut app);
+
+        assert!(screen.contains("Minor Service Outage  Updated 2024-01-10 10:30:00 UTC"));
+        assert!(screen.contains("● Actions"));
+        assert!(screen.contains("● Git Operations"));
+        assert!(screen.contains("> Degraded Actions (investigating)"));
+        assert!(screen.contains("Slow Pages builds (identified)"));
+        assert!(screen.contains("No upcoming maintenance"));
+    }
+
+    #[test]
+    fn test_filtered_overview() {
+        let mut app = filtered_app(ComponentFilter::new(["Git Operations"]));
+        let screen = screen(&mut app);
+
+        assert!(screen.contains("All Systems Operational  Updated"));
+        assert!(screen.contains("● Git Operations"));
+        assert!(!screen.contains("● Actions"));
+    }
+
+    #[test]
+    fn test_navigation() {
+        let mut app = app();
+
+        assert_eq!(press(&mut app, KeyCode::Down), Action::None);
+        assert!(screen(&mut app).contains("> Slow Pages builds"));
+        press(&mut app, KeyCode::Down);
+        assert!(screen(&mut app).contains("> Slow Pages builds"));
+
+        press(&mut app, KeyCode::Up);
+        press(&mut app, KeyCode::Enter);
+        let incident = screen(&mut app);
+        assert!(incident.contains("Degraded Actions"));
+        assert!(incident.contains("2024-01-10 10:05:00 UTC: investigating"));
+        assert!(incident.contains("We are investigating delays starting jobs."));
+
+        assert_eq!(press(&mut app, KeyCode::Char('r')), Action::Refresh);
+        press(&mut app, KeyCode::Esc);
+        assert!(screen(&mut app).contains("Unresolved Incidents"));
+        assert_eq!(press(&mut app, KeyCode::Char('q')), Action::Quit);
+        assert_eq!(
+            app.handle_key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)),
+            Action::Quit
+        );
+    }
+
+    #[test]
+    fn test_update_error() {
+        let mut app = app();
+        app.update(Err(anyhow!("Unable to connect")), Utc::now());
+
+        let screen = screen(&mut app);
+        assert!(screen.contains("Minor Service Outage"));
+        assert!(screen.contains("Error: Unable to connect"));
+    }
+}
@@ -0,0 +1,223 @@
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::models::{ComponentStatus, Impact, IncidentStatus, Indicator, SummaryInfo};
+
+/// A transition between two polls of the summary.
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Change {
+    /// The overall status indicator changed.
+    Indicator {
+        old: Indicator,
+        new: Indicator,
+        description: String,
+    },
+
+    /// A component's status changed.
+    Component {
+        name: String,
+        old: ComponentStatus,
+        new: ComponentStatus,
+    },
+
+    /// A new incident was opened.
+    IncidentOpened {
+        name: String,
+        impact: Impact,
+        status: IncidentStatus,
+        shortlink: String,
+    },
+
+    /// An open incident's status changed, e.g. from investigating to identified.
+    IncidentUpdated {
+        name: String,
+        old: IncidentStatus,
+        new: IncidentStatus,
+        shortlink: String,
+    },
+
+    /// An incident was resolved.
+    IncidentResolved { name: String, shortlink: String },
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::Indicator { description, .. } => write!(f, "Status: {description}"),
+            Change::Component { name, old, new } => {
+                write!(f, "{name}: {} -> {}", old.label(), new.label())
+            }
+            Change::IncidentOpened {
+                name,
+                status,
+                shortlink,
+                ..
+            } => write!(f, "Incident opened: {name} ({status}) {shortlink}"),
+            Change::IncidentUpdated {
+                name,
+                old,
+                new,
+                shortlink,
+            } => write!(f, "Incident updated: {name}: {old} -> {new} {shortlink}"),
+            Change::IncidentResolved { name, shortlink } => {
+                write!(f, "Incident resolved: {name} {shortlink}")
+            }
+        }
+    }
+}
+
+/// Finds the changes between two polls of the summary. Components and incidents are matched by
+/// id, and incidents that are no longer listed as unresolved are treated as resolved.
+pub fn diff(old: &SummaryInfo, new: &SummaryInfo) -> Vec<Change> {
+    let mut changes = Vec::new();
+
+    if old.status.indicator != new.status.indicator {
+        changes.push(Change::Indicator {
+            old: old.status.indicator.clone(),
+            new: new.status.indicator.clone(),
+            description: new.status.description.clone(),
+        });
+    }
+
+    for component in &new.components {
+        if let Some(previous) = old.components.iter().find(|c| c.id == component.id) {
+            if previous.status != component.status {
+                changes.push(Change::Component {
+                    name: component.name.clone(),
+                    old: previous.status.clone(),
+                    new: component.status.clone(),
+                });
+            }
+        }
+    }
+
+    for incident in &new.incidents {
+        let previous = old.incidents.iter().find(|i| i.id == incident.id);
+
+        if incident.status == IncidentStatus::Resolved {
+            if previous.is_some_and(|p| p.status != IncidentStatus::Resolved) {
+                changes.push(Change::IncidentResolved {
+                    name: incident.name.clone(),
+                    shortlink: incident.shortlink.clone(),
+                });
+            }
+            continue;
+        }
+
+        match previous {
+            None => changes.push(Change::IncidentOpened {
+                name: incident.name.clone(),
+                impact: incident.impact.clone(),
+                status: incident.status.clone(),
+                shortlink: incident.shortlink.clone(),
+            }),
+            Some(previous) if previous.status != incident.status => {
+                changes.push(Change::IncidentUpdated {
+                    name: incident.name.clone(),
+                    old: previous.status.clone(),
+                    new: incident.status.clone(),
+                    shortlink: incident.shortlink.clone(),
+                })
+            }
+            Some(_) => (),
+        }
+    }
+
+    for incident in &old.incidents {
+        if incident.status != IncidentStatus::Resolved
+            && !new.incidents.iter().any(|i| i.id == incident.id)
+        {
+            changes.push(Change::IncidentResolved {
+                name: incident.name.clone(),
+                shortlink: incident.shortlink.clone(),
+            });
+        }
+    }
+
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{diff, Change};
+    use crate::fixtures::summary;
+    use crate::models::{ComponentStatus, Impact, IncidentStatus, Indicator};
+
+    fn incident(id: &str, status: &str) -> String {
+        format!(
+            r#"{{"id": "{id}", "name": "Incident {id}", "impact": "minor", "status": "{status}", "shortlink": "https://stspg.io/{id}"}}"#
+        )
+    }
+
+    #[test]
+    fn test_no_changes() {
+        let old = summary("none", "operational", "");
+        let new = summary("none", "operational", "");
+
+        assert!(diff(&old, &new).is_empty());
+    }
+
+    #[test]
+    fn test_status_changes() {
+        let old = summary("none", "operational", "");
+        let new = summary("minor", "partial_outage", "");
+
+        assert_eq!(
+            diff(&old, &new),
+            vec![
+                Change::Indicator {
+                    old: Indicator::None,
+                    new: Indicator::Minor,
+                    description: "Status".to_string(),
+                },
+                Change::Component {
+                    name: "Actions".to_string(),
+                    old: ComponentStatus::Operational,
+                    new: ComponentStatus::PartialOutage,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_incident_changes() {
+        let old = summary(
+            "minor",
+            "operational",
+            &[
+                incident("x", "investigating"),
+                incident("y", "investigating"),
+            ]
+            .join(","),
+        );
+        let new = summary(
+            "minor",
+            "operational",
+            &[incident("y", "identified"), incident("z", "investigating")].join(","),
+        );
+
+        assert_eq!(
+            diff(&old, &new),
+            vec![
+                Change::IncidentUpdated {
+                    name: "Incident y".to_string(),
+                    old: IncidentStatus::Investigating,
+                    new: IncidentStatus::Identified,
+                    shortlink: "https://stspg.io/y".to_string(),
+                },
+                Change::IncidentOpened {
+                    name: "Incident z".to_string(),
+                    impact: Impact::Minor,
+                    status: IncidentStatus::Investigating,
+                    shortlink: "https://stspg.io/z".to_string(),
+                },
+                Change::IncidentResolved {
+                    name: "Incident x".to_string(),
+                    shortlink: "https://stspg.io/x".to_string(),
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,181 @@
+use std::{error::Error, fmt, io};
+
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+
+/// Why a response couldn't be retrieved from the status page.
+#[derive(Debug)]
+pub enum FetchError {
+    /// The server couldn't be reached, e.g. the DNS lookup or connection failed.
+    Connect { url: String, source: reqwest::Error },
+
+    /// The server didn't respond in time.
+    Timeout {
+        url: String,
+        source: Box<dyn Error + Send + Sync>,
+    },
+
+    /// The server responded with an error status.
+    Status { url: String, status: StatusCode },
+
+    /// The response doesn't match the expected schema. `path` is the offending field, e.g.
+    /// `components[3].status`.
+    Schema {
+        url: String,
+        path: String,
+        source: serde_json::Error,
+    },
+
+    /// Any other error sending the request or reading the response.
+    Request {
+        url: String,
+        source: Box<dyn Error + Send + Sync>,
+    },
+
+    /// Running offline and there's no cached response.
+    NotCached { url: String },
+}
+
+impl FetchError {
+    pub(crate) fn from_reqwest(url: &str, source: reqwest::Error) -> FetchError {
+        let url = url.to_string();
+
+        if let Some(status) = source.status() {
+            FetchError::Status { url, status }
+        } else if source.is_timeout() {
+            FetchError::Timeout {
+                url,
+                source: source.into(),
+            }
+        } else if source.is_connect() {
+            FetchError::Connect { url, source }
+        } else {
+            FetchError::Request {
+                url,
+                source: source.into(),
+            }
+        }
+    }
+
+    /// Classifies an error reading the response body.
+    pub(crate) fn from_io(url: &str, source: io::Error) -> FetchError {
+        let url = url.to_string();
+        let timed_out = source.kind() == io::ErrorKind::TimedOut
+            || source
+                .get_ref()
+                .and_then(|e| e.downcast_ref::<reqwest::Error>())
+                .is_some_and(reqwest::Error::is_timeout);
+
+        if timed_out {
+            FetchError::Timeout {
+                url,
+                source: source.into(),
+            }
+        } else {
+            FetchError::Request {
+                url,
+                source: source.into(),
+            }
+        }
+    }
+
+    /// The URL of the request that failed.
+    pub fn url(&self) -> &str {
+        match self {
+            FetchError::Connect { url, .. }
+            | FetchError::Timeout { url, .. }
+            | FetchError::Status { url, .. }
+            | FetchError::Schema { url, .. }
+            | FetchError::Request { url, .. }
+            | FetchError::NotCached { url } => url,
+        }
+    }
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::Connect { url, .. } => write!(f, "Unable to connect to {url}"),
+            FetchError::Timeout { url, .. } => write!(f, "Timed out waiting for {url}"),
+            FetchError::Status { url, status } => write!(f, "{url} responded with {status}"),
+            FetchError::Schema { url, path, .. } => {
+                write!(f, "Unexpected response from {url} at `{path}`")
+            }
+            FetchError::Request { url, .. } => write!(f, "Request to {url} failed"),
+            FetchError::NotCached { url } => write!(f, "No cached response for {url}"),
+        }
+    }
+}
+
+impl Error for FetchError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FetchError::Connect { source, .. } => Some(source),
+            FetchError::Timeout { source, .. } | FetchError::Request { source, .. } => {
+                Some(source.as_ref())
+            }
+            FetchError::Schema { source, .. } => Some(source),
+            FetchError::Status { .. } | FetchError::NotCached { .. } => None,
+        }
+    }
+}
+
+/// Deserializes a response body, recording the path to the field that doesn't match.
+pub(crate) fn parse<T: DeserializeOwned>(url: &str, body: &str) -> Result<T, FetchError> {
+    let deserializer = &mut serde_json::Deserializer::from_str(body);
+
+    serde_path_to_error::deserialize(deserializer).map_err(|e| FetchError::Schema {
+        url: url.to_string(),
+        path: e.path().to_string(),
+        source: e.into_inner(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use super::{parse, FetchError};
+    use crate::models::ComponentInfo;
+
+    #[test]
+    fn test_parse_schema_error() {
+        let data = r#"
+            {
+              "page": {"url": "https://www.githubstatus.com"},
+              "components": [
+                {"id": "a", "name": "Actions", "status": "operational"},
+                {"id": "b", "name": "Pages", "status": 3}
+              ]
+            }"#;
+
+        let err = parse::<ComponentInfo>("https://www.githubstatus.com", data).unwrap_err();
+
+        assert!(
+            matches!(err, FetchError::Schema { ref path, .. } if path == "components[1].status")
+        );
+        assert_eq!(
+            err.to_string(),
+            "Unexpected response from https://www.githubstatus.com at `components[1].status`"
+        );
+        assert!(err.source().unwrap().to_string().contains("invalid type"));
+    }
+
+    #[test]
+    fn test_parse_missing_field() {
+        let data =
+            r#"{"page": {"url": "https://www.githubstatus.com"}, "components": [{"id": "a"}]}"#;
+
+        let err = parse::<ComponentInfo>("https://www.githubstatus.com", data).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Unexpected response from https://www.githubstatus.com at `components[0]`"
+        );
+        assert!(err
+            .source()
+            .unwrap()
+            .to_string()
+            .contains("missing field `name`"));
+    }
+}
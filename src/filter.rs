@@ -0,0 +1,199 @@
+use std::collections::HashSet;
+
+use anyhow::{bail, Result};
+
+use crate::models::Component;
+
+/// Selects components by name or glob pattern, e.g. `Git*`, matched case-insensitively. An empty
+/// filter selects every component shown on the status page.
+#[derive(Clone, Debug, Default)]
+pub struct ComponentFilter {
+    patterns: Vec<String>,
+}
+
+impl ComponentFilter {
+    pub fn new<I, S>(patterns: I) -> ComponentFilter
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        ComponentFilter {
+            patterns: patterns
+                .into_iter()
+                .map(|p| p.as_ref().to_lowercase())
+                .collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+
+    pub fn matches(&self, name: &str) -> bool {
+        if self.is_empty() {
+            return true;
+        }
+
+        let name: Vec<char> = name.to_lowercase().chars().collect();
+        self.patterns.iter().any(|p| {
+            let pattern: Vec<char> = p.chars().collect();
+            glob_match(&pattern, &name)
+        })
+    }
+
+    /// Errors if a pattern doesn't match any component so a typo doesn't silently select nothing.
+    pub fn check(&self, components: &[Component]) -> Result<()> {
+        for pattern in &self.patterns {
+            let filter = ComponentFilter::new([pattern]);
+            if !components.iter().any(|c| filter.matches(&c.name)) {
+                bail!("No component matches {pattern:?}");
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The components matching the filter, or the ones shown on the status page if it is empty.
+    pub fn select<'a>(&self, components: &'a [Component]) -> Vec<&'a Component> {
+        components
+            .iter()
+            .filter(|c| {
+                if self.is_empty() {
+                    c.showcase
+                } else {
+                    self.matches(&c.name)
+                }
+            })
+            .collect()
+    }
+
+    /// Removes the components that don't match. Groups are kept if any of their children match
+    /// and every child of a matching group is kept, so the matches can still be shown in their
+    /// groups.
+    pub fn retain(&self, components: &mut Vec<Component>) -> Result<()> {
+        if self.is_empty() {
+            return Ok(());
+        }
+
+        self.check(components)?;
+
+        let matched: HashSet<String> = components
+            .iter()
+            .filter(|c| self.matches(&c.name))
+            .map(|c| c.id.clone())
+            .collect();
+        let groups: HashSet<String> = components
+            .iter()
+            .filter(|c| matched.contains(&c.id))
+            .filter_map(|c| c.group_id.clone())
+            .collect();
+
+        components.retain(|c| {
+            matched.contains(&c.id)
+                || groups.contains(&c.id)
+                || c.group_id.as_ref().is_some_and(|id| matched.contains(id))
+        });
+
+        Ok(())
+    }
+}
+
+/// Matches `text` against a pattern where `*` matches any run of characters and `?` matches a
+/// single character.
+fn glob_match(pattern: &[char], text: &[char]) -> bool {
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack = None;
+
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if let Some((star, matched)) = backtrack {
+            // Let the last `*` match one more character and try again.
+            backtrack = Some((star, matched + 1));
+            p = star + 1;
+            t = matched + 1;
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ComponentFilter;
+    use crate::models::ComponentInfo;
+
+    const COMPONENTS: &str = r#"
+        {
+          "page": {"url": "https://www.githubstatus.com", "updated_at": null},
+          "components": [
+            {"id": "a", "name": "Git Operations", "position": 1, "status": "operational"},
+            {"id": "b", "name": "API Requests", "position": 2, "status": "operational"},
+            {"id": "g", "name": "Packages", "group": true, "position": 3, "status": "operational"},
+            {"id": "c", "name": "npm", "group_id": "g", "position": 1, "status": "operational"},
+            {"id": "d", "name": "Container registry", "group_id": "g", "position": 2, "status": "operational"},
+            {"id": "e", "name": "Actions", "position": 4, "status": "operational", "showcase": false}
+          ]
+        }"#;
+
+    fn names(info: &ComponentInfo) -> Vec<&str> {
+        info.components.iter().map(|c| c.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_matches() {
+        let filter = ComponentFilter::new(["git*", "API REQUESTS", "n?m"]);
+
+        assert!(filter.matches("Git Operations"));
+        assert!(filter.matches("api requests"));
+        assert!(filter.matches("npm"));
+        assert!(!filter.matches("Pages"));
+        assert!(!filter.matches("Actions"));
+        assert!(ComponentFilter::default().matches("Actions"));
+    }
+
+    #[test]
+    fn test_select() {
+        let info: ComponentInfo = serde_json::from_str(COMPONENTS).unwrap();
+
+        let selected = ComponentFilter::default().select(&info.components);
+        assert_eq!(selected.len(), 5);
+
+        let selected = ComponentFilter::new(["actions"]).select(&info.components);
+        assert_eq!(selected.len(), 1);
+    }
+
+    #[test]
+    fn test_retain_child() {
+        let mut info: ComponentInfo = serde_json::from_str(COMPONENTS).unwrap();
+        ComponentFilter::new(["git*", "npm"])
+            .retain(&mut info.components)
+            .unwrap();
+
+        assert_eq!(names(&info), vec!["Git Operations", "Packages", "npm"]);
+    }
+
+    #[test]
+    fn test_retain_group() {
+        let mut info: ComponentInfo = serde_json::from_str(COMPONENTS).unwrap();
+        ComponentFilter::new(["packages"])
+            .retain(&mut info.components)
+            .unwrap();
+
+        assert_eq!(names(&info), vec!["Packages", "npm", "Container registry"]);
+    }
+
+    #[test]
+    fn test_retain_no_match() {
+        let mut info: ComponentInfo = serde_json::from_str(COMPONENTS).unwrap();
+        let result = ComponentFilter::new(["pages"]).retain(&mut info.components);
+
+        assert!(result.is_err());
+    }
+}
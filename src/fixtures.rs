@@ -0,0 +1,129 @@
+//! Test fixtures shared by the library and the binary, which both include this file.
+
+// Each crate only uses some of these.
+#![allow(dead_code)]
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+
+use serde::de::DeserializeOwned;
+
+/// A summary with an incident, one operational component, and one with a partial outage.
+pub const SUMMARY: &str = r#"
+    {
+      "page": {"url": "https://www.githubstatus.com", "updated_at": null},
+      "status": {"description": "Minor Service Outage", "indicator": "minor"},
+      "components": [
+        {"id": "8l4ygp009s5s", "description": "Git operations", "name": "Git Operations", "status": "operational"},
+        {"id": "br0l2tvcx85d", "description": "GitHub Actions", "name": "Actions", "status": "partial_outage"}
+      ],
+      "incidents": [
+        {
+          "id": "1",
+          "name": "Degraded Actions",
+          "impact": "minor",
+          "status": "investigating",
+          "shortlink": "https://stspg.io/1",
+          "created_at": "2024-01-10T10:00:00Z",
+          "incident_updates": [
+            {
+              "body": "We are investigating delays starting jobs.",
+              "status": "investigating",
+              "created_at": "2024-01-10T10:05:00Z"
+            }
+          ]
+        },
+        {
+          "id": "2",
+          "name": "Slow Pages builds",
+          "impact": "minor",
+          "status": "identified",
+          "shortlink": "https://stspg.io/2"
+        }
+      ]
+    }"#;
+
+/// A summary with the indicator, an operational "Git Operations" component, an "Actions"
+/// component with the status, and the comma separated incidents.
+pub fn summary<T: DeserializeOwned>(indicator: &str, actions: &str, incidents: &str) -> T {
+    let data = format!(
+        r#"
+        {{
+          "page": {{"url": "https://www.githubstatus.com", "updated_at": null}},
+          "status": {{"description": "Status", "indicator": "{indicator}"}},
+          "components": [
+            {{"id": "a", "name": "Git Operations", "status": "operational"}},
+            {{"id": "b", "name": "Actions", "status": "{actions}"}}
+          ],
+          "incidents": [{incidents}]
+        }}"#
+    );
+
+    serde_json::from_str(&data).unwrap()
+}
+
+/// A request received by [`serve`].
+pub struct Request {
+    /// The request line and headers, lowercased.
+    pub head: String,
+    pub body: String,
+}
+
+/// A response with the status and no body.
+pub fn empty_response(status: u16) -> String {
+    format!("HTTP/1.1 {status} Status\r\nContent-Length: 0\r\nConnection: close\r\n\r\n")
+}
+
+/// A successful response with `body` as JSON.
+pub fn json_response(body: &str) -> String {
+    format!(
+        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )
+}
+
+/// Sends one raw response per request and returns the server's base URL along with a receiver
+/// for the requests.
+pub fn serve(responses: Vec<String>) -> (String, Receiver<Request>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (tx, rx) = mpsc::channel();
+
+    thread::spawn(move || {
+        for response in responses {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut head = String::new();
+            loop {
+                let read = reader.read_line(&mut head).unwrap();
+                if read == 0 || head.ends_with("\r\n\r\n") {
+                    break;
+                }
+            }
+
+            let head = head.to_lowercase();
+            let content_length = head
+                .lines()
+                .find_map(|line| line.strip_prefix("content-length:"))
+                .map_or(0, |value| value.trim().parse().unwrap());
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+
+            // The test may not care about the requests.
+            let _ = tx.send(Request {
+                head,
+                body: String::from_utf8(body).unwrap(),
+            });
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+        }
+    });
+
+    (format!("http://{addr}"), rx)
+}
+
+/// Serves `body` as a JSON response to a single request and returns the server's base URL.
+pub fn serve_once(body: &str) -> String {
+    serve(vec![json_response(body)]).0
+}
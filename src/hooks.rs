@@ -0,0 +1,199 @@
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+};
+
+use anyhow::{bail, Result};
+use colored::*;
+use github_status::{Change, SummaryInfo};
+use serde::Serialize;
+
+/// Shell commands run by `watch` when the status changes.
+#[derive(Debug, Default)]
+pub struct Hooks {
+    /// Run whenever anything changes.
+    pub on_change: Option<String>,
+
+    /// Run when the selected components stop being operational.
+    pub on_degraded: Option<String>,
+
+    /// Run when the selected components are operational again.
+    pub on_recovered: Option<String>,
+}
+
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Event {
+    Change,
+    Degraded,
+    Recovered,
+}
+
+impl Event {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Event::Change => "change",
+            Event::Degraded => "degraded",
+            Event::Recovered => "recovered",
+        }
+    }
+}
+
+/// The JSON written to the command's stdin.
+#[derive(Serialize)]
+struct Payload<'a> {
+    event: Event,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    provider: Option<&'a str>,
+
+    old: &'a SummaryInfo,
+    new: &'a SummaryInfo,
+    changes: &'a [Change],
+}
+
+impl Hooks {
+    pub fn is_empty(&self) -> bool {
+        self.on_change.is_none() && self.on_degraded.is_none() && self.on_recovered.is_none()
+    }
+
+    /// Runs the commands for the transition from `old` to `new` on the `provider`'s status page.
+    /// `was_operational` and `is_operational` are whether the selected components were
+    /// operational before and after. A failing command is reported but doesn't stop the watch.
+    pub fn run(
+        &self,
+        provider: Option<&str>,
+        old: &SummaryInfo,
+        new: &SummaryInfo,
+        changes: &[Change],
+        was_operational: bool,
+        is_operational: bool,
+    ) {
+        if changes.is_empty() {
+            return;
+        }
+
+        let mut hooks = vec![(Event::Change, &self.on_change)];
+        if was_operational && !is_operational {
+            hooks.push((Event::Degraded, &self.on_degraded));
+        } else if !was_operational && is_operational {
+            hooks.push((Event::Recovered, &self.on_recovered));
+        }
+
+        for (event, command) in hooks {
+            if let Some(command) = command {
+                let payload = Payload {
+                    event,
+                    provider,
+                    old,
+                    new,
+                    changes,
+                };
+
+                if let Err(e) = run_command(command, &payload) {
+                    eprintln!(
+                        "{}",
+                        format!("Error running the {} command: {e}", event.as_str()).red()
+                    );
+                }
+            }
+        }
+    }
+}
+
+fn shell(command: &str) -> Command {
+    if cfg!(windows) {
+        let mut shell = Command::new("cmd");
+        shell.args(["/C", command]);
+        shell
+    } else {
+        let mut shell = Command::new("sh");
+        shell.args(["-c", command]);
+        shell
+    }
+}
+
+fn run_command(command: &str, payload: &Payload) -> Result<()> {
+    let mut process = shell(command);
+    if let Some(provider) = payload.provider {
+        process.env("GITHUB_STATUS_PROVIDER", provider);
+    }
+
+    let mut child = process
+        .env("GITHUB_STATUS_EVENT", payload.event.as_str())
+        .env(
+            "GITHUB_STATUS_OLD_INDICATOR",
+            payload.old.status.indicator.as_str(),
+        )
+        .env(
+            "GITHUB_STATUS_NEW_INDICATOR",
+            payload.new.status.indicator.as_str(),
+        )
+        .env(
+            "GITHUB_STATUS_OLD_DESCRIPTION",
+            &payload.old.status.description,
+        )
+        .env(
+            "GITHUB_STATUS_NEW_DESCRIPTION",
+            &payload.new.status.description,
+        )
+        .stdin(Stdio::piped())
+        .spawn()?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // The command may exit without reading stdin so a broken pipe isn't an error.
+        let _ = stdin.write_all(serde_json::to_string(payload)?.as_bytes());
+    }
+
+    let status = child.wait()?;
+    if !status.success() {
+        bail!("{command:?} exited with {status}");
+    }
+
+    Ok(())
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use std::fs;
+
+    use github_status::diff;
+
+    use super::Hooks;
+    use crate::fixtures::summary;
+
+    #[test]
+    fn test_run_hooks() {
+        let dir = std::env::temp_dir().join(format!("github-status-hooks-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let out = |name: &str| dir.join(name).display().to_string();
+
+        let hooks = Hooks {
+            on_change: Some(format!("cat > {}", out("change.json"))),
+            on_degraded: Some(format!(
+                "echo \"$GITHUB_STATUS_EVENT $GITHUB_STATUS_OLD_INDICATOR $GITHUB_STATUS_NEW_INDICATOR\" > {}",
+                out("degraded.txt")
+            )),
+            on_recovered: Some(format!("touch {}", out("recovered.txt"))),
+        };
+
+        let old = summary("none", "operational", "");
+        let new = summary("major", "major_outage", "");
+        hooks.run(None, &old, &new, &diff(&old, &new), true, false);
+
+        let payload: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(out("change.json")).unwrap()).unwrap();
+        assert_eq!(payload["event"], "change");
+        assert_eq!(payload["old"]["status"]["indicator"], "none");
+        assert_eq!(payload["new"]["status"]["indicator"], "major");
+        assert_eq!(payload["changes"].as_array().unwrap().len(), 2);
+
+        assert_eq!(
+            fs::read_to_string(out("degraded.txt")).unwrap(),
+            "degraded none major\n"
+        );
+        assert!(!dir.join("recovered.txt").exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
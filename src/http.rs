@@ -0,0 +1,177 @@
+use std::{
+    fs,
+    io::{self, Read},
+    path::PathBuf,
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result};
+use reqwest::{
+    blocking::{Client, RequestBuilder, Response},
+    Certificate, StatusCode,
+};
+
+/// Identifies the client to the Statuspage servers.
+pub const USER_AGENT: &str = concat!(
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION"),
+    " (+",
+    env!("CARGO_PKG_HOMEPAGE"),
+    ")"
+);
+
+/// Settings for the HTTP client shared by every request.
+///
+/// Proxies set with the `HTTPS_PROXY`, `HTTP_PROXY`, `ALL_PROXY`, and `NO_PROXY` environment
+/// variables are always used.
+#[derive(Debug, Clone)]
+pub struct HttpOptions {
+    /// The maximum time to wait for a connection to be established.
+    pub connect_timeout: Duration,
+
+    /// The maximum time to wait for the response, or for more of its body to arrive.
+    pub read_timeout: Duration,
+
+    /// The maximum time a whole request can take, including reading the response. If not set a
+    /// slow response is read for as long as it keeps arriving.
+    pub timeout: Option<Duration>,
+
+    /// The number of times a failed request is retried.
+    pub retries: u32,
+
+    /// The delay before the first retry, doubled for each retry after it.
+    pub retry_delay: Duration,
+
+    /// A PEM file with extra CA certificates to trust, e.g. for a corporate proxy.
+    pub ca_cert: Option<PathBuf>,
+}
+
+impl Default for HttpOptions {
+    fn default() -> Self {
+        HttpOptions {
+            connect_timeout: Duration::from_secs(10),
+            read_timeout: Duration::from_secs(30),
+            timeout: None,
+            retries: 3,
+            retry_delay: Duration::from_millis(500),
+            ca_cert: None,
+        }
+    }
+}
+
+impl HttpOptions {
+    /// Builds a client with these settings.
+    pub fn build(&self) -> Result<Client> {
+        let mut builder = Client::builder()
+            .user_agent(USER_AGENT)
+            .connect_timeout(self.connect_timeout)
+            // The blocking client applies this to waiting for the response and to each read of
+            // the body, so reading the body in chunks makes it an idle timeout.
+            .timeout(
+                self.timeout
+                    .map_or(self.read_timeout, |t| t.min(self.read_timeout)),
+            );
+
+        if let Some(path) = &self.ca_cert {
+            let pem = fs::read(path).with_context(|| {
+                format!("Unable to read CA certificates from {}", path.display())
+            })?;
+            let certs = Certificate::from_pem_bundle(&pem)
+                .with_context(|| format!("Invalid CA certificates in {}", path.display()))?;
+            builder = builder.tls_certs_merge(certs);
+        }
+
+        Ok(builder.build()?)
+    }
+
+    /// Sends the request, retrying up to `retries` times with exponential backoff on connection
+    /// errors, timeouts, rate limiting, and server errors. Only use this for requests that are
+    /// safe to repeat.
+    pub fn send(&self, request: RequestBuilder) -> reqwest::Result<Response> {
+        let mut delay = self.retry_delay;
+        let mut attempt = 0;
+
+        loop {
+            // Requests without a streaming body can always be cloned.
+            let result = request
+                .try_clone()
+                .expect("requests with a buffered body can be cloned")
+                .send();
+
+            if !should_retry(&result) || attempt >= self.retries {
+                return result;
+            }
+
+            thread::sleep(delay);
+            delay *= 2;
+            attempt += 1;
+        }
+    }
+}
+
+/// Returns `true` if sending the request again might give a different result.
+fn should_retry(result: &reqwest::Result<Response>) -> bool {
+    match result {
+        Ok(response) => is_transient(response.status()),
+        Err(e) => e.is_connect() || e.is_timeout(),
+    }
+}
+
+/// Reads the body of a response to a request sent at `sent_at`, failing if it takes longer than
+/// `timeout`.
+pub(crate) fn read_body(
+    mut response: Response,
+    sent_at: Instant,
+    timeout: Option<Duration>,
+) -> io::Result<String> {
+    let mut body = Vec::new();
+    let mut buf = [0; 8192];
+
+    loop {
+        let read = match response.read(&mut buf) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        body.extend_from_slice(&buf[..read]);
+
+        if let Some(timeout) = timeout.filter(|t| sent_at.elapsed() > *t) {
+            return Err(io::Error::new(
+                io::ErrorKind::TimedOut,
+                format!("the response took longer than {timeout:?}"),
+            ));
+        }
+    }
+
+    Ok(String::from_utf8_lossy(&body).into_owned())
+}
+
+/// Returns `true` if a request that got the status might succeed when retried, i.e. it was rate
+/// limited or hit a server error.
+pub(crate) fn is_transient(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::StatusCode;
+
+    use super::{is_transient, USER_AGENT};
+
+    #[test]
+    fn test_user_agent() {
+        assert!(USER_AGENT.starts_with("github-status/"));
+    }
+
+    #[test]
+    fn test_is_transient() {
+        assert!(is_transient(StatusCode::SERVICE_UNAVAILABLE));
+        assert!(is_transient(StatusCode::TOO_MANY_REQUESTS));
+        assert!(!is_transient(StatusCode::OK));
+        assert!(!is_transient(StatusCode::NOT_MODIFIED));
+        assert!(!is_transient(StatusCode::NOT_FOUND));
+    }
+}
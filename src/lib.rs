@@ -0,0 +1,34 @@
+//! A client for the [GitHub status](https://www.githubstatus.com) API, or any other page hosted
+//! on Atlassian Statuspage.
+//!
+//! ```no_run
+//! use github_status::{Indicator, StatusClient};
+//!
+//! let summary = StatusClient::default().summary()?;
+//! if summary.status.indicator != Indicator::None {
+//!     println!("GitHub is having issues: {}", summary.status.description);
+//! }
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
+mod archive;
+mod cache;
+mod client;
+mod diff;
+mod error;
+mod filter;
+#[cfg(test)]
+mod fixtures;
+mod http;
+mod models;
+mod uptime;
+
+pub use crate::archive::{Archive, ComponentState, History, HistoryEntry, Recorder, Snapshot};
+pub use crate::cache::Cache;
+pub use crate::client::{Endpoint, StatusClient, DEFAULT_BASE_URL};
+pub use crate::diff::{diff, Change};
+pub use crate::error::FetchError;
+pub use crate::filter::ComponentFilter;
+pub use crate::http::{HttpOptions, USER_AGENT};
+pub use crate::models::*;
+pub use crate::uptime::{ComponentUptime, UptimeReport};
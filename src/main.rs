@@ -1,28 +1,407 @@
-mod github_api;
+mod config;
+mod dashboard;
+#[cfg(test)]
+mod fixtures;
+mod hooks;
+mod metrics;
 mod options;
+mod output;
+mod providers;
+mod record;
+mod table;
+mod webhook;
 
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+use std::process::ExitCode;
 use std::time::Duration;
 
-use crate::github_api::{ComponentInfo, IncidentInfo, MaintenanceInfo, StatusInfo, SummaryInfo};
-use crate::options::{Command, Options};
+use crate::config::Settings;
+use crate::hooks::Hooks;
+use crate::options::{ColorChoice, Command, Options, OutputFormat, Report, TimeArg};
+use crate::output::{Print, Style, TimeFormat, WatchOptions, WatchTarget};
+use crate::providers::CombinedSummary;
+use crate::webhook::Webhook;
 
-use clap::Parser;
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, TimeDelta, Utc};
+use clap::{CommandFactory, FromArgMatches};
+use colored::*;
+use github_status::{
+    Archive, Cache, ComponentFilter, History, HttpOptions, Indicator, StatusClient, UptimeReport,
+};
+use terminal_size::{terminal_size, Width};
 
-fn main() {
-    let opt = Options::parse();
+const EXIT_ERROR: u8 = 1;
+const EXIT_MINOR: u8 = 3;
+const EXIT_MAJOR: u8 = 4;
+const EXIT_CRITICAL: u8 = 5;
+const EXIT_TIMEOUT: u8 = 124;
+
+fn main() -> ExitCode {
+    let matches = Options::command().get_matches();
+    let mut opt = Options::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+    let verbose = opt.verbose;
+
+    let result = Settings::load(opt.config.as_deref(), opt.profile.as_deref())
+        .and_then(|settings| settings.apply(&mut opt, &matches))
+        .and_then(|_| run(opt));
+
+    match result {
+        Ok(code) => code,
+        Err(e) => {
+            output::print_error(&e, verbose);
+            ExitCode::from(EXIT_ERROR)
+        }
+    }
+}
+
+fn run(opt: Options) -> Result<ExitCode> {
+    let http = HttpOptions {
+        connect_timeout: Duration::from_secs(opt.connect_timeout),
+        read_timeout: Duration::from_secs(opt.read_timeout),
+        timeout: opt.request_timeout.map(Duration::from_secs),
+        retries: opt.retries,
+        ca_cert: opt.ca_cert,
+        ..HttpOptions::default()
+    };
+    // A single saved response would be shown for every provider.
+    if let Command::Summary {
+        providers: Some(_), ..
+    }
+    | Command::Watch {
+        providers: Some(_), ..
+    }
+    | Command::Record {
+        providers: Some(_), ..
+    } = &opt.command
+    {
+        if opt.input.is_some() {
+            bail!("--input can't be used with --providers");
+        }
+    }
+    let input = match &opt.input {
+        Some(path) => Some((path.display().to_string(), read_input(path)?)),
+        None => None,
+    };
+    // Polling commands always check for changes so they never act on a stale response.
+    let max_age = match opt.command {
+        Command::Dashboard { .. }
+        | Command::Record { .. }
+        | Command::Serve { .. }
+        | Command::Wait { .. }
+        | Command::Watch { .. } => Duration::ZERO,
+        _ => Duration::from_secs(opt.cache_max_age),
+    };
+    let cache_dir = Cache::default_dir().filter(|_| !opt.no_cache);
+    let archive = opt
+        .history_dir
+        .or_else(Archive::default_dir)
+        .map(Archive::new);
+
+    let make_client = |base_url: &str| -> Result<StatusClient> {
+        let mut client = StatusClient::with_options(base_url, http.clone())?.offline(opt.offline);
+        if let Some((source, body)) = &input {
+            client = client.with_input(source, body);
+        }
+        if let Some(dir) = &cache_dir {
+            client = client.with_cache(Cache::new(dir, max_age));
+        }
+
+        Ok(client)
+    };
+    let client = make_client(&opt.base_url)?;
+
+    let format = opt.format;
+    match opt.color {
+        ColorChoice::Always => colored::control::set_override(true),
+        ColorChoice::Never => colored::control::set_override(false),
+        ColorChoice::Auto => {}
+    }
+    let style = Style {
+        time: TimeFormat {
+            tz: opt.tz,
+            relative: opt.relative,
+        },
+        color: colored::control::SHOULD_COLORIZE.should_colorize(),
+        // Detected before a pager takes over stdout.
+        width: terminal_size().map(|(Width(width), _)| usize::from(width)),
+    };
 
     match opt.command {
-        Command::ActiveMaintenance { pager } => MaintenanceInfo::print_activate(pager),
-        Command::AllIncidents { pager } => IncidentInfo::print_all(pager),
-        Command::AllScheduledMaintenances { pager } => MaintenanceInfo::print_all(pager),
-        Command::Component { pager } => ComponentInfo::print_info(pager),
-        Command::Status { pager } => StatusInfo::print_info(pager),
-        Command::Summary { pager } => SummaryInfo::print_info(pager),
-        Command::UnresolvedIncidents { pager } => IncidentInfo::print_unresolved(pager),
-        Command::UpcomingMaintenance { pager } => MaintenanceInfo::print_upcoming(pager),
+        Command::ActiveMaintenance { pager } => client
+            .active_maintenances()?
+            .output(pager, format, &style)?,
+        Command::AllIncidents { pager } => client.incidents()?.output(pager, format, &style)?,
+        Command::AllScheduledMaintenances { pager } => client
+            .scheduled_maintenances()?
+            .output(pager, format, &style)?,
+        Command::Component { pager, components } => {
+            let mut info = client.components()?;
+            ComponentFilter::new(components).retain(&mut info.components)?;
+            info.output(pager, format, &style)?
+        }
+        Command::Dashboard {
+            duration,
+            components,
+        } => dashboard::run(
+            client,
+            ComponentFilter::new(components),
+            Duration::from_secs(duration * 60),
+            style.time,
+        )?,
+        Command::History {
+            since,
+            from,
+            to,
+            at,
+            pager,
+            components,
+        } => {
+            let resolve = |time: TimeArg| time.resolve(opt.tz).map_err(anyhow::Error::msg);
+            let (since, until) = match at {
+                Some(at) => {
+                    let at = resolve(at)?;
+                    (at, at)
+                }
+                None => {
+                    let until = to.map(resolve).transpose()?.unwrap_or_else(Utc::now);
+                    let since = match from {
+                        Some(from) => resolve(from)?,
+                        None => period_start(until, since)?,
+                    };
+                    (since, until)
+                }
+            };
+
+            let archive = archive.context("Unable to find a directory for the history archive")?;
+            History::new(
+                &archive.snapshots(client.base_url())?,
+                since,
+                until,
+                &ComponentFilter::new(components),
+            )
+            .output(pager, format, &style)?
+        }
+        Command::Incident { id, pager } => client.incident(&id)?.output(pager, format, &style)?,
+        Command::Report {
+            report:
+                Report::Uptime {
+                    since,
+                    pager,
+                    components,
+                },
+        } => {
+            let until = Utc::now();
+            let since = period_start(until, since)?;
+            let filter = ComponentFilter::new(components);
+            let history = match &archive {
+                Some(archive) => {
+                    History::new(
+                        &archive.snapshots(client.base_url())?,
+                        since,
+                        until,
+                        &filter,
+                    )
+                    .entries
+                }
+                None => Vec::new(),
+            };
+
+            let report = UptimeReport::new(
+                &client.incidents()?.incidents,
+                &history,
+                since,
+                until,
+                &filter,
+            );
+            if report.since > report.requested_since {
+                let warning = format!(
+                    "Warning: Older incidents aren't available, the report starts at {}",
+                    style.time.absolute().format(&report.since.fixed_offset())
+                );
+                eprintln!("{}", warning.yellow());
+            }
+
+            report.output(pager, format, &style)?
+        }
+        Command::Record {
+            duration,
+            providers,
+        } => {
+            let clients = match providers {
+                Some(path) => providers::load(&path)?
+                    .iter()
+                    .map(|provider| make_client(&provider.base_url))
+                    .collect::<Result<Vec<_>>>()?,
+                None => vec![client],
+            };
+            let archive = archive.context("Unable to find a directory for the history archive")?;
+
+            record::record(
+                &clients,
+                &archive,
+                Duration::from_secs(duration * 60),
+                opt.verbose,
+            )?
+        }
+        Command::Serve { listen, duration } => {
+            metrics::serve(client, &listen, Duration::from_secs(duration * 60))?
+        }
+        Command::Status { pager } => {
+            let info = client.status()?;
+            info.output(pager, format, &style)?;
+            return Ok(indicator_exit_code(&info.status.indicator));
+        }
+        Command::Summary {
+            pager,
+            providers: Some(path),
+            ..
+        } => {
+            let combined = CombinedSummary::fetch(&providers::load(&path)?, make_client);
+            combined.output(pager, format, &style)?;
+            return Ok(match combined.indicator() {
+                Some(indicator) => indicator_exit_code(&indicator),
+                None => ExitCode::from(EXIT_ERROR),
+            });
+        }
+        Command::Summary {
+            pager,
+            components,
+            providers: None,
+        } => {
+            let mut info = client.summary()?;
+            info.retain(&ComponentFilter::new(components))?;
+            info.output(pager, format, &style)?;
+            return Ok(indicator_exit_code(&info.status.indicator));
+        }
+        Command::UnresolvedIncidents { pager } => client
+            .unresolved_incidents()?
+            .output(pager, format, &style)?,
+        Command::UpcomingMaintenance { pager } => client
+            .upcoming_maintenances()?
+            .output(pager, format, &style)?,
         Command::Watch {
             duration,
             cancel_when_operational,
-        } => SummaryInfo::watch(Duration::from_secs(duration * 60), cancel_when_operational),
+            changes,
+            record,
+            on_change,
+            on_degraded,
+            on_recovered,
+            webhook,
+            webhook_format,
+            webhook_retries,
+            components,
+            providers,
+        } => output::watch(
+            &match providers {
+                Some(path) => providers::load(&path)?
+                    .into_iter()
+                    .map(|provider| {
+                        Ok(WatchTarget {
+                            client: make_client(&provider.base_url)?,
+                            filter: ComponentFilter::new(provider.components),
+                            name: Some(provider.name),
+                        })
+                    })
+                    .collect::<Result<Vec<_>>>()?,
+                None => vec![WatchTarget {
+                    name: None,
+                    client: client.clone(),
+                    filter: ComponentFilter::new(components),
+                }],
+            },
+            &WatchOptions {
+                duration: Duration::from_secs(duration * 60),
+                cancel_when_operational,
+                changes_only: changes,
+                hooks: Hooks {
+                    on_change,
+                    on_degraded,
+                    on_recovered,
+                },
+                webhook: webhook.map(|url| {
+                    Webhook::new(url, webhook_format, webhook_retries)
+                        .with_client(client.http().clone())
+                }),
+                archive: if record {
+                    Some(archive.context("Unable to find a directory for the history archive")?)
+                } else {
+                    None
+                },
+                verbose: opt.verbose,
+            },
+            format,
+            &style,
+            &mut io::stdout(),
+        )?,
+        Command::Wait {
+            duration,
+            timeout,
+            components,
+        } => {
+            let operational = client.wait(
+                minutes(duration, "--duration")?,
+                timeout.map(|t| minutes(t, "--timeout")).transpose()?,
+                &ComponentFilter::new(components),
+                |e| output::print_error(e, opt.verbose),
+            )?;
+
+            if !operational {
+                eprintln!(
+                    "{}",
+                    "Timed out waiting for services to be operational".red()
+                );
+                return Ok(ExitCode::from(EXIT_TIMEOUT));
+            }
+
+            if format != OutputFormat::Json {
+                println!("All services are operational");
+            }
+        }
+    };
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Reads the contents of `path`, or stdin if it is `-`.
+fn read_input(path: &Path) -> Result<String> {
+    if path == Path::new("-") {
+        let mut body = String::new();
+        io::stdin()
+            .read_to_string(&mut body)
+            .context("Unable to read the input from stdin")?;
+        return Ok(body);
+    }
+
+    fs::read_to_string(path)
+        .with_context(|| format!("Unable to read the input from {}", path.display()))
+}
+
+/// A number of minutes passed as `option`.
+fn minutes(value: u64, option: &str) -> Result<Duration> {
+    value
+        .checked_mul(60)
+        .map(Duration::from_secs)
+        .with_context(|| format!("{option} is too large"))
+}
+
+/// The start of a `--since` period ending at `until`.
+fn period_start(until: DateTime<Utc>, since: TimeDelta) -> Result<DateTime<Utc>> {
+    until
+        .checked_sub_signed(since)
+        .context("The --since period goes back too far")
+}
+
+/// Maps the status indicator to the exit code so CI jobs can react to GitHub's health.
+fn indicator_exit_code(indicator: &Indicator) -> ExitCode {
+    match indicator {
+        Indicator::None => ExitCode::SUCCESS,
+        Indicator::Minor => ExitCode::from(EXIT_MINOR),
+        Indicator::Major => ExitCode::from(EXIT_MAJOR),
+        Indicator::Critical => ExitCode::from(EXIT_CRITICAL),
+        Indicator::Unknown(_) => ExitCode::from(EXIT_ERROR),
     }
 }
@@ -0,0 +1,244 @@
+use std::{
+    fmt::Write,
+    sync::{Arc, Mutex},
+    thread,
+    time::Duration,
+};
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use github_status::{ComponentFilter, ComponentStatus, IncidentStatus, StatusClient, SummaryInfo};
+use tiny_http::{Header, Response, Server};
+
+const COMPONENT_STATUSES: [ComponentStatus; 5] = [
+    ComponentStatus::Operational,
+    ComponentStatus::DegradedPerformance,
+    ComponentStatus::PartialOutage,
+    ComponentStatus::MajorOutage,
+    ComponentStatus::UnderMaintenance,
+];
+
+/// The result of the most recent poll of the summary.
+#[derive(Debug, Default)]
+struct State {
+    /// The last summary that was fetched successfully.
+    summary: Option<SummaryInfo>,
+
+    /// Set if the most recent poll succeeded.
+    success: bool,
+
+    polled_at: Option<DateTime<Utc>>,
+}
+
+/// Escapes a Prometheus label value.
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Renders the state in the Prometheus text exposition format.
+fn render(state: &State) -> String {
+    let mut out = String::new();
+
+    out.push_str(
+        "# HELP github_status_scrape_success Whether the last poll of the status page succeeded.\n",
+    );
+    out.push_str("# TYPE github_status_scrape_success gauge\n");
+    let _ = writeln!(
+        out,
+        "github_status_scrape_success {}",
+        u8::from(state.success)
+    );
+
+    if let Some(polled_at) = state.polled_at {
+        out.push_str("# HELP github_status_last_poll_timestamp_seconds When the status page was last polled.\n");
+        out.push_str("# TYPE github_status_last_poll_timestamp_seconds gauge\n");
+        let _ = writeln!(
+            out,
+            "github_status_last_poll_timestamp_seconds {}",
+            polled_at.timestamp()
+        );
+    }
+
+    let Some(summary) = &state.summary else {
+        return out;
+    };
+
+    out.push_str("# HELP github_status_indicator The overall status: 0 none, 1 minor, 2 major, 3 critical, 4 unknown.\n");
+    out.push_str("# TYPE github_status_indicator gauge\n");
+    let _ = writeln!(
+        out,
+        "github_status_indicator {}",
+        summary.status.indicator.severity()
+    );
+
+    out.push_str(
+        "# HELP github_status_component_status Set to 1 for the current status of each component.\n",
+    );
+    out.push_str("# TYPE github_status_component_status gauge\n");
+    for component in ComponentFilter::default().select(&summary.components) {
+        let mut statuses = COMPONENT_STATUSES.to_vec();
+        if !statuses.contains(&component.status) {
+            statuses.push(component.status.clone());
+        }
+
+        for status in statuses {
+            let _ = writeln!(
+                out,
+                "github_status_component_status{{component=\"{}\",id=\"{}\",status=\"{}\"}} {}",
+                escape(&component.name),
+                escape(&component.id),
+                escape(status.as_str()),
+                u8::from(component.status == status)
+            );
+        }
+    }
+
+    let unresolved = summary
+        .incidents
+        .iter()
+        .filter(|i| i.status != IncidentStatus::Resolved)
+        .count();
+    out.push_str("# HELP github_status_unresolved_incidents The number of unresolved incidents.\n");
+    out.push_str("# TYPE github_status_unresolved_incidents gauge\n");
+    let _ = writeln!(out, "github_status_unresolved_incidents {unresolved}");
+
+    let active = summary
+        .scheduled_maintenances
+        .iter()
+        .filter(|m| {
+            matches!(
+                m.status,
+                IncidentStatus::InProgress | IncidentStatus::Verifying
+            )
+        })
+        .count();
+    out.push_str(
+        "# HELP github_status_active_maintenances The number of scheduled maintenances in progress.\n",
+    );
+    out.push_str("# TYPE github_status_active_maintenances gauge\n");
+    let _ = writeln!(out, "github_status_active_maintenances {active}");
+
+    out
+}
+
+/// Polls the summary every `duration` and serves the result as Prometheus metrics on `/metrics`.
+pub fn serve(client: StatusClient, listen: &str, duration: Duration) -> Result<()> {
+    let server = Server::http(listen).map_err(|e| anyhow!("Unable to listen on {listen}: {e}"))?;
+    let state = Arc::new(Mutex::new(State::default()));
+
+    let poller = Arc::clone(&state);
+    thread::spawn(move || loop {
+        let result = client.summary();
+        let mut state = poller.lock().unwrap();
+        state.polled_at = Some(Utc::now());
+        state.success = result.is_ok();
+        if let Ok(summary) = result {
+            state.summary = Some(summary);
+        }
+        drop(state);
+
+        thread::sleep(duration);
+    });
+
+    println!("Serving metrics on http://{listen}/metrics");
+
+    let content_type =
+        Header::from_bytes("Content-Type", "text/plain; version=0.0.4; charset=utf-8").unwrap();
+
+    for request in server.incoming_requests() {
+        let response = if path(request.url()) == "/metrics" {
+            let body = render(&state.lock().unwrap());
+            Response::from_string(body).with_header(content_type.clone())
+        } else {
+            Response::from_string("Not Found").with_status_code(404)
+        };
+
+        // A client that disconnects early shouldn't stop the server.
+        let _ = request.respond(response);
+    }
+
+    Ok(())
+}
+
+/// The path of a request URL, without the query string, e.g. `/metrics` for `/metrics?foo=bar`.
+fn path(url: &str) -> &str {
+    url.split_once('?').map_or(url, |(path, _)| path)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use github_status::SummaryInfo;
+
+    use super::{path, render, State};
+
+    #[test]
+    fn test_path() {
+        assert_eq!(path("/metrics"), "/metrics");
+        assert_eq!(path("/metrics?foo=bar"), "/metrics");
+        assert_eq!(path("/?metrics"), "/");
+    }
+
+    #[test]
+    fn test_render_without_summary() {
+        let state = State::default();
+
+        assert_eq!(
+            render(&state),
+            "# HELP github_status_scrape_success Whether the last poll of the status page succeeded.\n\
+             # TYPE github_status_scrape_success gauge\n\
+             github_status_scrape_success 0\n"
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let data = r#"
+            {
+              "page": {"url": "https://www.githubstatus.com", "updated_at": null},
+              "status": {"description": "Minor Service Outage", "indicator": "minor"},
+              "components": [
+                {"id": "a", "name": "Actions \"CI\"", "status": "partial_outage"},
+                {"id": "b", "name": "Hidden", "status": "operational", "showcase": false}
+              ],
+              "incidents": [
+                {"id": "i", "name": "Incident", "impact": "minor", "status": "investigating", "shortlink": "https://stspg.io/i"}
+              ],
+              "scheduled_maintenances": [
+                {"name": "Maintenance", "impact": "maintenance", "status": "in_progress", "shortlink": "https://stspg.io/m"},
+                {"name": "Upcoming", "impact": "maintenance", "status": "scheduled", "shortlink": "https://stspg.io/u"}
+              ]
+            }"#;
+        let summary: SummaryInfo = serde_json::from_str(data).unwrap();
+        let state = State {
+            summary: Some(summary),
+            success: true,
+            polled_at: Some(Utc.with_ymd_and_hms(2022, 9, 5, 8, 7, 25).unwrap()),
+        };
+
+        let metrics: Vec<String> = render(&state)
+            .lines()
+            .filter(|l| !l.starts_with('#'))
+            .map(String::from)
+            .collect();
+
+        assert_eq!(
+            metrics,
+            vec![
+                "github_status_scrape_success 1",
+                "github_status_last_poll_timestamp_seconds 1662365245",
+                "github_status_indicator 1",
+                r#"github_status_component_status{component="Actions \"CI\"",id="a",status="operational"} 0"#,
+                r#"github_status_component_status{component="Actions \"CI\"",id="a",status="degraded_performance"} 0"#,
+                r#"github_status_component_status{component="Actions \"CI\"",id="a",status="partial_outage"} 1"#,
+                r#"github_status_component_status{component="Actions \"CI\"",id="a",status="major_outage"} 0"#,
+                r#"github_status_component_status{component="Actions \"CI\"",id="a",status="under_maintenance"} 0"#,
+                "github_status_unresolved_incidents 1",
+                "github_status_active_maintenances 1",
+            ]
+        );
+    }
+}
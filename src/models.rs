@@ -0,0 +1,571 @@
+use std::fmt;
+
+use anyhow::Result;
+use chrono::{DateTime, FixedOffset, TimeDelta};
+use serde::{Deserialize, Serialize};
+
+use crate::filter::ComponentFilter;
+
+/// Defines an enum for a string value returned by the API. Values that aren't known are kept in
+/// `Unknown` so new values added to the API don't cause deserialization to fail.
+macro_rules! api_enum {
+    ($(#[$meta:meta])* $name:ident { $($variant:ident => $value:literal,)+ }) => {
+        $(#[$meta])*
+        #[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq, Hash)]
+        #[serde(from = "String", into = "String")]
+        pub enum $name {
+            $($variant,)+
+            Unknown(String),
+        }
+
+        impl $name {
+            /// The value used by the API.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $value,)+
+                    $name::Unknown(value) => value,
+                }
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(value: String) -> Self {
+                match value.as_str() {
+                    $($value => $name::$variant,)+
+                    _ => $name::Unknown(value),
+                }
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(value: $name) -> Self {
+                value.as_str().to_string()
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+    };
+}
+
+api_enum!(
+    /// The status of a single component.
+    ComponentStatus {
+        Operational => "operational",
+        DegradedPerformance => "degraded_performance",
+        PartialOutage => "partial_outage",
+        MajorOutage => "major_outage",
+        UnderMaintenance => "under_maintenance",
+    }
+);
+
+impl ComponentStatus {
+    /// The overall status indicator this component status corresponds to.
+    pub fn indicator(&self) -> Indicator {
+        match self {
+            ComponentStatus::Operational => Indicator::None,
+            ComponentStatus::DegradedPerformance | ComponentStatus::UnderMaintenance => {
+                Indicator::Minor
+            }
+            ComponentStatus::PartialOutage => Indicator::Major,
+            ComponentStatus::MajorOutage => Indicator::Critical,
+            ComponentStatus::Unknown(value) => Indicator::Unknown(value.clone()),
+        }
+    }
+
+    pub fn label(&self) -> &str {
+        match self {
+            ComponentStatus::Operational => "operational",
+            ComponentStatus::DegradedPerformance => "degraded performance",
+            ComponentStatus::PartialOutage => "partial outage",
+            ComponentStatus::MajorOutage => "major outage",
+            ComponentStatus::UnderMaintenance => "under maintenance",
+            ComponentStatus::Unknown(value) => value,
+        }
+    }
+}
+
+api_enum!(
+    /// The impact of an incident or scheduled maintenance.
+    Impact {
+        None => "none",
+        Minor => "minor",
+        Major => "major",
+        Critical => "critical",
+        Maintenance => "maintenance",
+    }
+);
+
+api_enum!(
+    /// The overall status indicator for the page.
+    Indicator {
+        None => "none",
+        Minor => "minor",
+        Major => "major",
+        Critical => "critical",
+    }
+);
+
+impl Indicator {
+    /// Orders the indicators from least to most severe. Unknown values are treated as the most
+    /// severe so they aren't hidden.
+    pub fn severity(&self) -> u8 {
+        match self {
+            Indicator::None => 0,
+            Indicator::Minor => 1,
+            Indicator::Major => 2,
+            Indicator::Critical => 3,
+            Indicator::Unknown(_) => 4,
+        }
+    }
+
+    /// The description Statuspage shows for the indicator, e.g. "Minor Service Outage".
+    pub fn description(&self) -> &str {
+        match self {
+            Indicator::None => "All Systems Operational",
+            Indicator::Minor => "Minor Service Outage",
+            Indicator::Major => "Partial System Outage",
+            Indicator::Critical => "Major Service Outage",
+            Indicator::Unknown(value) => value,
+        }
+    }
+}
+
+api_enum!(
+    /// The status of an incident, scheduled maintenance, or one of their updates.
+    IncidentStatus {
+        Investigating => "investigating",
+        Identified => "identified",
+        Monitoring => "monitoring",
+        Resolved => "resolved",
+        Postmortem => "postmortem",
+        Scheduled => "scheduled",
+        InProgress => "in_progress",
+        Verifying => "verifying",
+        Completed => "completed",
+    }
+);
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct Component {
+    pub description: Option<String>,
+
+    /// Set if this component is a group of other components.
+    #[serde(default)]
+    pub group: bool,
+
+    /// The id of the group this component belongs to, if any.
+    pub group_id: Option<String>,
+
+    pub id: String,
+    pub name: String,
+
+    /// The order the component is displayed in on the status page.
+    #[serde(default)]
+    pub position: u32,
+
+    /// Set if the component is shown on the status page.
+    #[serde(default = "default_showcase")]
+    pub showcase: bool,
+
+    pub status: ComponentStatus,
+    pub updated_at: Option<DateTime<FixedOffset>>,
+}
+
+fn default_showcase() -> bool {
+    true
+}
+
+/// A top level component along with its child components if it is a group.
+#[derive(Debug)]
+pub struct ComponentNode<'a> {
+    pub component: &'a Component,
+    pub children: Vec<&'a Component>,
+}
+
+/// Arranges the components into groups in the order they appear on the status page. Components
+/// whose group can't be found are treated as top level components.
+pub fn component_tree(components: &[Component]) -> Vec<ComponentNode<'_>> {
+    let mut sorted: Vec<&Component> = components.iter().collect();
+    sorted.sort_by_key(|c| c.position);
+
+    let is_child = |c: &Component| {
+        c.group_id
+            .as_ref()
+            .is_some_and(|id| sorted.iter().any(|g| g.group && &g.id == id))
+    };
+
+    sorted
+        .iter()
+        .filter(|c| !is_child(c))
+        .map(|&component| ComponentNode {
+            component,
+            children: sorted
+                .iter()
+                .filter(|c| component.group && c.group_id.as_ref() == Some(&component.id))
+                .copied()
+                .collect(),
+        })
+        .collect()
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct Incident {
+    /// The components affected by the incident.
+    #[serde(default)]
+    pub components: Vec<Component>,
+
+    pub created_at: Option<DateTime<FixedOffset>>,
+    pub id: String,
+    pub impact: Impact,
+    pub incident_updates: Option<Vec<IncidentUpdate>>,
+    pub name: String,
+    pub resolved_at: Option<DateTime<FixedOffset>>,
+    pub shortlink: String,
+    pub status: IncidentStatus,
+    pub updated_at: Option<DateTime<FixedOffset>>,
+}
+
+impl Incident {
+    /// Checks if the incident has the id or shortlink. The shortlink can be the full URL or just
+    /// the code at the end of it.
+    pub fn matches(&self, id_or_shortlink: &str) -> bool {
+        let value = id_or_shortlink.trim_end_matches('/');
+
+        self.id == value
+            || self.shortlink == value
+            || self
+                .shortlink
+                .rsplit_once('/')
+                .is_some_and(|(_, code)| code == value)
+    }
+
+    /// The updates from oldest to newest.
+    pub fn timeline(&self) -> Vec<&IncidentUpdate> {
+        let mut updates: Vec<&IncidentUpdate> = self.incident_updates.iter().flatten().collect();
+        updates.sort_by_key(|u| u.created_at);
+
+        updates
+    }
+
+    /// The time from when the incident was created until it was resolved.
+    pub fn time_to_resolution(&self) -> Option<TimeDelta> {
+        Some(self.resolved_at?.signed_duration_since(self.created_at?))
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct IncidentUpdate {
+    /// The components whose status was changed by the update.
+    pub affected_components: Option<Vec<AffectedComponent>>,
+
+    pub body: String,
+    pub created_at: Option<DateTime<FixedOffset>>,
+    pub status: IncidentStatus,
+    pub updated_at: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AffectedComponent {
+    pub code: Option<String>,
+    pub name: String,
+    pub new_status: ComponentStatus,
+    pub old_status: ComponentStatus,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct Page {
+    pub url: String,
+    pub updated_at: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ScheduledMaintenance {
+    pub created_at: Option<DateTime<FixedOffset>>,
+    pub impact: Impact,
+    pub incident_updates: Option<Vec<IncidentUpdate>>,
+    pub name: String,
+    pub shortlink: String,
+    pub status: IncidentStatus,
+    pub updated_at: Option<DateTime<FixedOffset>>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct Status {
+    pub description: String,
+    pub indicator: Indicator,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ComponentInfo {
+    pub page: Page,
+    pub components: Vec<Component>,
+}
+
+impl ComponentInfo {
+    /// The components arranged into groups in the order they appear on the status page.
+    pub fn tree(&self) -> Vec<ComponentNode<'_>> {
+        component_tree(&self.components)
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct MaintenanceInfo {
+    pub page: Page,
+    pub scheduled_maintenances: Vec<ScheduledMaintenance>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct StatusInfo {
+    pub page: Page,
+    pub status: Status,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct SummaryInfo {
+    pub page: Page,
+    pub status: Status,
+    pub components: Vec<Component>,
+
+    /// The unresolved incidents.
+    #[serde(default)]
+    pub incidents: Vec<Incident>,
+
+    /// The upcoming and in progress scheduled maintenances.
+    #[serde(default)]
+    pub scheduled_maintenances: Vec<ScheduledMaintenance>,
+}
+
+impl SummaryInfo {
+    /// The components arranged into groups in the order they appear on the status page.
+    pub fn tree(&self) -> Vec<ComponentNode<'_>> {
+        component_tree(&self.components)
+    }
+
+    /// Checks if the components selected by `filter` are operational.
+    pub fn is_operational(&self, filter: &ComponentFilter) -> Result<bool> {
+        filter.check(&self.components)?;
+
+        Ok(filter
+            .select(&self.components)
+            .iter()
+            .all(|c| c.status == ComponentStatus::Operational))
+    }
+
+    /// The overall status indicator, or if `filter` isn't empty the indicator for the worst status
+    /// of the components it selects.
+    pub fn indicator(&self, filter: &ComponentFilter) -> Indicator {
+        if filter.is_empty() {
+            return self.status.indicator.clone();
+        }
+
+        filter
+            .select(&self.components)
+            .iter()
+            .map(|c| c.status.indicator())
+            .max_by_key(|i| i.severity())
+            .unwrap_or(Indicator::None)
+    }
+
+    /// Keeps only the components selected by `filter`, and unless it is empty replaces the
+    /// overall status with the worst status of those components so the two agree.
+    pub fn retain(&mut self, filter: &ComponentFilter) -> Result<()> {
+        if filter.is_empty() {
+            return Ok(());
+        }
+
+        let indicator = self.indicator(filter);
+        filter.retain(&mut self.components)?;
+        self.status = Status {
+            description: indicator.description().to_string(),
+            indicator,
+        };
+
+        Ok(())
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct IncidentInfo {
+    pub page: Page,
+    pub incidents: Vec<Incident>,
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeDelta;
+
+    use super::ComponentFilter;
+    use super::ComponentInfo;
+    use super::ComponentStatus;
+    use super::Impact;
+    use super::Incident;
+    use super::IncidentStatus;
+    use super::Indicator;
+    use super::SummaryInfo;
+    use crate::fixtures::SUMMARY;
+
+    #[test]
+    fn test_api_enums() {
+        let status: ComponentStatus = serde_json::from_str(r#""partial_outage""#).unwrap();
+        assert_eq!(status, ComponentStatus::PartialOutage);
+        assert_eq!(status.label(), "partial outage");
+
+        let impact: Impact = serde_json::from_str(r#""critical""#).unwrap();
+        assert_eq!(impact, Impact::Critical);
+
+        let status: IncidentStatus = serde_json::from_str(r#""in_progress""#).unwrap();
+        assert_eq!(status, IncidentStatus::InProgress);
+        assert_eq!(serde_json::to_string(&status).unwrap(), r#""in_progress""#);
+    }
+
+    #[test]
+    fn test_api_enum_unknown() {
+        let indicator: Indicator = serde_json::from_str(r#""catastrophic""#).unwrap();
+        assert_eq!(indicator, Indicator::Unknown("catastrophic".to_string()));
+        assert_eq!(
+            serde_json::to_string(&indicator).unwrap(),
+            r#""catastrophic""#
+        );
+    }
+
+    #[test]
+    fn test_component_tree() {
+        let data = r#"
+            {
+              "page": {"url": "https://www.githubstatus.com", "updated_at": null},
+              "components": [
+                {"id": "c", "name": "Child B", "group_id": "g", "position": 3, "status": "operational"},
+                {"id": "g", "name": "Group", "group": true, "position": 2, "status": "major_outage"},
+                {"id": "b", "name": "Child A", "group_id": "g", "position": 2, "status": "major_outage"},
+                {"id": "a", "name": "Standalone", "position": 1, "status": "operational"},
+                {"id": "o", "name": "Orphan", "group_id": "missing", "position": 4, "status": "operational"}
+              ]
+            }"#;
+
+        let info: ComponentInfo = serde_json::from_str(data).unwrap();
+        let tree: Vec<(&str, Vec<&str>)> = info
+            .tree()
+            .iter()
+            .map(|n| {
+                (
+                    n.component.name.as_str(),
+                    n.children.iter().map(|c| c.name.as_str()).collect(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            tree,
+            vec![
+                ("Standalone", vec![]),
+                ("Group", vec!["Child A", "Child B"]),
+                ("Orphan", vec![]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_incident() {
+        let data = r#"
+            {
+              "components": [
+                {"id": "b13yz5g2cw10", "name": "API Requests", "status": "operational"}
+              ],
+              "created_at": "2014-05-14T14:22:39.441-06:00",
+              "id": "cp306tmzcl0y",
+              "impact": "major",
+              "incident_updates": [
+                {
+                  "affected_components": [
+                    {"code": "b13yz5g2cw10", "name": "API Requests", "old_status": "partial_outage", "new_status": "operational"}
+                  ],
+                  "body": "This incident has been resolved.",
+                  "created_at": "2014-05-14T16:35:21.711-06:00",
+                  "status": "resolved",
+                  "updated_at": "2014-05-14T16:35:21.711-06:00"
+                },
+                {
+                  "body": "We are investigating reports of degraded performance.",
+                  "created_at": "2014-05-14T14:22:40.301-06:00",
+                  "status": "investigating",
+                  "updated_at": "2014-05-14T14:22:40.301-06:00"
+                }
+              ],
+              "name": "Degraded performance for API Requests",
+              "resolved_at": "2014-05-14T16:35:21.711-06:00",
+              "shortlink": "https://stspg.io/abc123",
+              "status": "resolved",
+              "updated_at": "2014-05-14T16:35:21.711-06:00"
+            }"#;
+
+        let incident: Incident = serde_json::from_str(data).unwrap();
+
+        assert!(incident.matches("cp306tmzcl0y"));
+        assert!(incident.matches("https://stspg.io/abc123"));
+        assert!(incident.matches("abc123"));
+        assert!(!incident.matches("abc"));
+
+        let statuses: Vec<&IncidentStatus> =
+            incident.timeline().iter().map(|u| &u.status).collect();
+        assert_eq!(
+            statuses,
+            vec![&IncidentStatus::Investigating, &IncidentStatus::Resolved]
+        );
+
+        assert_eq!(
+            incident.time_to_resolution(),
+            Some(TimeDelta::milliseconds(7_962_270))
+        );
+    }
+
+    #[test]
+    fn test_is_operational() {
+        let info: SummaryInfo = serde_json::from_str(SUMMARY).unwrap();
+
+        assert!(!info.is_operational(&ComponentFilter::default()).unwrap());
+        assert!(info
+            .is_operational(&ComponentFilter::new(["git operations"]))
+            .unwrap());
+        assert!(!info
+            .is_operational(&ComponentFilter::new(["Git Operations", "Actions"]))
+            .unwrap());
+        assert!(info
+            .is_operational(&ComponentFilter::new(["Pages"]))
+            .is_err());
+    }
+
+    #[test]
+    fn test_indicator() {
+        let info: SummaryInfo = serde_json::from_str(SUMMARY).unwrap();
+
+        assert_eq!(
+            info.indicator(&ComponentFilter::default()),
+            Indicator::Minor
+        );
+        assert_eq!(
+            info.indicator(&ComponentFilter::new(["git*"])),
+            Indicator::None
+        );
+        assert_eq!(
+            info.indicator(&ComponentFilter::new(["*"])),
+            Indicator::Major
+        );
+    }
+
+    #[test]
+    fn test_retain() {
+        let mut info: SummaryInfo = serde_json::from_str(SUMMARY).unwrap();
+        info.retain(&ComponentFilter::default()).unwrap();
+        assert_eq!(info.status.description, "Minor Service Outage");
+        assert_eq!(info.components.len(), 2);
+
+        info.retain(&ComponentFilter::new(["git*"])).unwrap();
+        assert_eq!(info.status.indicator, Indicator::None);
+        assert_eq!(info.status.description, "All Systems Operational");
+        assert_eq!(info.components.len(), 1);
+    }
+}
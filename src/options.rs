@@ -1,59 +1,545 @@
-use clap::{Parser, Subcommand};
+use std::path::PathBuf;
+
+use clap::{ArgAction, Parser, Subcommand, ValueEnum};
+use serde::Deserialize;
+
+use chrono::{DateTime, FixedOffset, Local, NaiveDateTime, NaiveTime, TimeDelta, TimeZone, Utc};
+use chrono_tz::Tz;
+use github_status::DEFAULT_BASE_URL;
+
+use crate::webhook::WebhookFormat;
 
 #[derive(Debug, Parser)]
 #[clap(author, version, about = "Checks the status of GitHub")]
 pub struct Options {
+    #[clap(
+        long,
+        global = true,
+        env = "GITHUB_STATUS_BASE_URL",
+        default_value = DEFAULT_BASE_URL,
+        help = "The base URL of the Statuspage to check"
+    )]
+    pub base_url: String,
+
+    #[clap(
+        long,
+        global = true,
+        value_enum,
+        default_value_t = OutputFormat::Text,
+        help = "The format to use for the output"
+    )]
+    pub format: OutputFormat,
+
+    #[clap(
+        long,
+        global = true,
+        value_parser = parse_tz,
+        help = "The time zone to display times in, e.g. UTC or America/New_York. Defaults to the local time zone"
+    )]
+    pub tz: Option<Tz>,
+
+    #[clap(
+        long,
+        global = true,
+        action = ArgAction::Set,
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true",
+        default_value_t = false,
+        help = "Display times relative to now, e.g. 12 minutes ago"
+    )]
+    pub relative: bool,
+
+    #[clap(
+        long,
+        global = true,
+        value_enum,
+        default_value_t = ColorChoice::Auto,
+        help = "When to color the output"
+    )]
+    pub color: ColorChoice,
+
+    #[clap(
+        long,
+        global = true,
+        default_value_t = 30,
+        help = "The maximum number of seconds to wait for a response, or for more of it to arrive"
+    )]
+    pub read_timeout: u64,
+
+    #[clap(
+        long,
+        global = true,
+        help = "The maximum number of seconds a whole request can take. Unlimited by default"
+    )]
+    pub request_timeout: Option<u64>,
+
+    #[clap(
+        long,
+        global = true,
+        default_value_t = 10,
+        help = "The maximum number of seconds to wait for a connection"
+    )]
+    pub connect_timeout: u64,
+
+    #[clap(
+        long,
+        global = true,
+        default_value_t = 3,
+        help = "The number of times to retry a request after a connection error, timeout, or server error"
+    )]
+    pub retries: u32,
+
+    #[clap(
+        long,
+        global = true,
+        env = "GITHUB_STATUS_CA_CERT",
+        help = "A PEM file with extra CA certificates to trust"
+    )]
+    pub ca_cert: Option<PathBuf>,
+
+    #[clap(
+        long,
+        global = true,
+        env = "GITHUB_STATUS_CACHE_MAX_AGE",
+        default_value_t = 60,
+        help = "The number of seconds a cached response is used before checking for changes"
+    )]
+    pub cache_max_age: u64,
+
+    #[clap(long, global = true, help = "Don't read or write the response cache")]
+    pub no_cache: bool,
+
+    #[clap(
+        long,
+        global = true,
+        conflicts_with = "no_cache",
+        help = "Only use cached responses without connecting to the network"
+    )]
+    pub offline: bool,
+
+    #[clap(
+        long,
+        global = true,
+        help = "Read the API response from a JSON file, or stdin with -, instead of the network"
+    )]
+    pub input: Option<PathBuf>,
+
+    #[clap(
+        long,
+        global = true,
+        env = "GITHUB_STATUS_HISTORY_DIR",
+        help = "The directory of the status history archive. Defaults to github-status/history in the user's data directory"
+    )]
+    pub history_dir: Option<PathBuf>,
+
+    #[clap(
+        long,
+        global = true,
+        env = "GITHUB_STATUS_CONFIG",
+        help = "The config file to read defaults from. Defaults to github-status/config.toml in the user's config directory"
+    )]
+    pub config: Option<PathBuf>,
+
+    #[clap(
+        long,
+        global = true,
+        env = "GITHUB_STATUS_PROFILE",
+        help = "The profile in the config file to use"
+    )]
+    pub profile: Option<String>,
+
+    #[clap(
+        short,
+        long,
+        global = true,
+        help = "Print the full chain of causes when an error occurs"
+    )]
+    pub verbose: bool,
+
     #[clap(subcommand)]
     pub command: Command,
 }
 
+pub(crate) fn parse_tz(value: &str) -> Result<Tz, String> {
+    value
+        .parse()
+        .map_err(|_| format!("{value:?} is not a known time zone"))
+}
+
+/// Parses a period like `30d`, `12h`, `90m`, or `2w`.
+pub(crate) fn parse_period(value: &str) -> Result<TimeDelta, String> {
+    let invalid = || format!("{value:?} is not a period like 30d, 12h, or 90m");
+
+    let split = value.len() - value.chars().last().map_or(0, char::len_utf8);
+    let count: i64 = value[..split].parse().map_err(|_| invalid())?;
+    let period = match &value[split..] {
+        "m" => TimeDelta::try_minutes(count),
+        "h" => TimeDelta::try_hours(count),
+        "d" => TimeDelta::try_days(count),
+        "w" => TimeDelta::try_weeks(count),
+        _ => None,
+    };
+
+    period
+        .filter(|p| *p > TimeDelta::zero())
+        .ok_or_else(invalid)
+}
+
+/// A point in time passed on the command line. Times without an offset are in the `--tz` time
+/// zone, or the local one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimeArg {
+    /// An RFC 3339 timestamp, e.g. `2024-01-10T03:12:00Z`.
+    Exact(DateTime<FixedOffset>),
+
+    /// A date and time, e.g. `2024-01-10 03:12`.
+    DateTime(NaiveDateTime),
+
+    /// A time today, e.g. `03:12`.
+    Time(NaiveTime),
+}
+
+impl TimeArg {
+    pub fn resolve(&self, tz: Option<Tz>) -> Result<DateTime<Utc>, String> {
+        match tz {
+            Some(tz) => self.resolve_in(&tz),
+            None => self.resolve_in(&Local),
+        }
+    }
+
+    fn resolve_in<Z: TimeZone>(&self, tz: &Z) -> Result<DateTime<Utc>, String> {
+        let naive = match self {
+            TimeArg::Exact(time) => return Ok(time.with_timezone(&Utc)),
+            TimeArg::DateTime(naive) => *naive,
+            TimeArg::Time(time) => Utc::now().with_timezone(tz).date_naive().and_time(*time),
+        };
+
+        tz.from_local_datetime(&naive)
+            .earliest()
+            .map(|time| time.with_timezone(&Utc))
+            .ok_or_else(|| format!("{naive} doesn't exist in the time zone"))
+    }
+}
+
+pub(crate) fn parse_time(value: &str) -> Result<TimeArg, String> {
+    if let Ok(time) = DateTime::parse_from_rfc3339(value) {
+        return Ok(TimeArg::Exact(time));
+    }
+    for format in [
+        "%Y-%m-%d %H:%M:%S",
+        "%Y-%m-%d %H:%M",
+        "%Y-%m-%dT%H:%M:%S",
+        "%Y-%m-%dT%H:%M",
+    ] {
+        if let Ok(time) = NaiveDateTime::parse_from_str(value, format) {
+            return Ok(TimeArg::DateTime(time));
+        }
+    }
+    for format in ["%H:%M:%S", "%H:%M"] {
+        if let Ok(time) = NaiveTime::parse_from_str(value, format) {
+            return Ok(TimeArg::Time(time));
+        }
+    }
+
+    Err(format!(
+        "{value:?} is not a time like 2024-01-10T03:12:00Z, \"2024-01-10 03:12\", or 03:12"
+    ))
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    /// Colored, human readable output.
+    Text,
+
+    /// Aligned columns that fit the width of the terminal.
+    Table,
+
+    /// The API models serialized as JSON.
+    Json,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ColorChoice {
+    /// Color unless the NO_COLOR or CLICOLOR=0 environment variables are set.
+    Auto,
+
+    Always,
+
+    Never,
+}
+
 #[derive(Debug, Subcommand)]
 pub enum Command {
     /// Gets a list of active maintenance.
     ActiveMaintenance {
-        #[clap(short, long, help = "If set the output will be displayed in a pager")]
+        #[clap(
+            short,
+            long,
+            action = ArgAction::Set,
+            num_args = 0..=1,
+            require_equals = true,
+            default_missing_value = "true",
+            default_value_t = false,
+            help = "If set the output will be displayed in a pager"
+        )]
         pager: bool,
     },
 
     /// Gets a list of all incidents.
     AllIncidents {
-        #[clap(short, long, help = "If set the output will be displayed in a pager")]
+        #[clap(
+            short,
+            long,
+            action = ArgAction::Set,
+            num_args = 0..=1,
+            require_equals = true,
+            default_missing_value = "true",
+            default_value_t = false,
+            help = "If set the output will be displayed in a pager"
+        )]
         pager: bool,
     },
 
     /// Gets a list of the 50 most recent scheduled maintenances.
     AllScheduledMaintenances {
-        #[clap(short, long, help = "If set the output will be displayed in a pager")]
+        #[clap(
+            short,
+            long,
+            action = ArgAction::Set,
+            num_args = 0..=1,
+            require_equals = true,
+            default_missing_value = "true",
+            default_value_t = false,
+            help = "If set the output will be displayed in a pager"
+        )]
         pager: bool,
     },
 
     /// Status of each component.
     Component {
-        #[clap(short, long, help = "If set the output will be displayed in a pager")]
+        #[clap(
+            short,
+            long,
+            action = ArgAction::Set,
+            num_args = 0..=1,
+            require_equals = true,
+            default_missing_value = "true",
+            default_value_t = false,
+            help = "If set the output will be displayed in a pager"
+        )]
         pager: bool,
+
+        #[clap(
+            long = "component",
+            help = "Only include components matching the name or glob pattern, e.g. \"Git*\". Can be passed multiple times"
+        )]
+        components: Vec<String>,
+    },
+
+    /// Shows an interactive dashboard that refreshes automatically.
+    Dashboard {
+        #[clap(
+            short,
+            long,
+            default_value_t = 1,
+            help = "The duration to wait between refreshes in minutes"
+        )]
+        duration: u64,
+
+        #[clap(
+            long = "component",
+            help = "Only include components matching the name or glob pattern, e.g. \"Git*\". Can be passed multiple times"
+        )]
+        components: Vec<String>,
+    },
+
+    /// Shows the recorded status of the components over a period.
+    History {
+        #[clap(
+            long,
+            default_value = "24h",
+            value_parser = parse_period,
+            help = "How far back to show, e.g. 24h, 7d, or 90m"
+        )]
+        since: TimeDelta,
+
+        #[clap(
+            long,
+            value_parser = parse_time,
+            conflicts_with = "since",
+            help = "The start of the period, e.g. \"2024-01-10 03:00\" or 2024-01-10T03:00:00Z"
+        )]
+        from: Option<TimeArg>,
+
+        #[clap(
+            long,
+            value_parser = parse_time,
+            help = "The end of the period. Defaults to now"
+        )]
+        to: Option<TimeArg>,
+
+        #[clap(
+            long,
+            value_parser = parse_time,
+            conflicts_with_all = ["since", "from", "to"],
+            help = "Only show the status at this time, e.g. 03:12 or \"2024-01-10 03:12\""
+        )]
+        at: Option<TimeArg>,
+
+        #[clap(
+            short,
+            long,
+            action = ArgAction::Set,
+            num_args = 0..=1,
+            require_equals = true,
+            default_missing_value = "true",
+            default_value_t = false,
+            help = "If set the output will be displayed in a pager"
+        )]
+        pager: bool,
+
+        #[clap(
+            long = "component",
+            help = "Only include components matching the name or glob pattern, e.g. \"Git*\". Can be passed multiple times"
+        )]
+        components: Vec<String>,
+    },
+
+    /// Shows the full timeline of a recent incident.
+    Incident {
+        #[clap(help = "The id or shortlink of the incident")]
+        id: String,
+
+        #[clap(
+            short,
+            long,
+            action = ArgAction::Set,
+            num_args = 0..=1,
+            require_equals = true,
+            default_missing_value = "true",
+            default_value_t = false,
+            help = "If set the output will be displayed in a pager"
+        )]
+        pager: bool,
+    },
+
+    /// Quietly polls the status and records it in the history archive.
+    Record {
+        #[clap(
+            short,
+            long,
+            default_value_t = 1,
+            help = "The duration to wait between polling in minutes"
+        )]
+        duration: u64,
+
+        #[clap(
+            long,
+            env = "GITHUB_STATUS_PROVIDERS",
+            help = "A TOML file listing several Statuspage providers to record together"
+        )]
+        providers: Option<PathBuf>,
+    },
+
+    /// Reports computed from the incident history.
+    Report {
+        #[clap(subcommand)]
+        report: Report,
+    },
+
+    /// Serves the current status as Prometheus metrics on /metrics.
+    Serve {
+        #[clap(
+            short,
+            long,
+            default_value = "127.0.0.1:9617",
+            help = "The address to listen on"
+        )]
+        listen: String,
+
+        #[clap(
+            short,
+            long,
+            default_value_t = 1,
+            help = "The duration to wait between polling in minutes"
+        )]
+        duration: u64,
     },
 
     /// Gets the current status
     Status {
-        #[clap(short, long, help = "If set the output will be displayed in a pager")]
+        #[clap(
+            short,
+            long,
+            action = ArgAction::Set,
+            num_args = 0..=1,
+            require_equals = true,
+            default_missing_value = "true",
+            default_value_t = false,
+            help = "If set the output will be displayed in a pager"
+        )]
         pager: bool,
     },
 
     /// Gets a summary for the current GitHub status.
     Summary {
-        #[clap(short, long, help = "If set the output will be displayed in a pager")]
+        #[clap(
+            short,
+            long,
+            action = ArgAction::Set,
+            num_args = 0..=1,
+            require_equals = true,
+            default_missing_value = "true",
+            default_value_t = false,
+            help = "If set the output will be displayed in a pager"
+        )]
         pager: bool,
+
+        #[clap(
+            long = "component",
+            help = "Only include components matching the name or glob pattern, e.g. \"Git*\". Can be passed multiple times"
+        )]
+        components: Vec<String>,
+
+        #[clap(
+            long,
+            env = "GITHUB_STATUS_PROVIDERS",
+            conflicts_with = "components",
+            help = "A TOML file listing several Statuspage providers to check together"
+        )]
+        providers: Option<PathBuf>,
     },
 
     /// Gets a list of any unresolved incidents.
     UnresolvedIncidents {
-        #[clap(short, long, help = "If set the output will be displayed in a pager")]
+        #[clap(
+            short,
+            long,
+            action = ArgAction::Set,
+            num_args = 0..=1,
+            require_equals = true,
+            default_missing_value = "true",
+            default_value_t = false,
+            help = "If set the output will be displayed in a pager"
+        )]
         pager: bool,
     },
 
     /// Gets a list of upcoming maintenance
     UpcomingMaintenance {
-        #[clap(short, long, help = "If set the output will be displayed in a pager")]
+        #[clap(
+            short,
+            long,
+            action = ArgAction::Set,
+            num_args = 0..=1,
+            require_equals = true,
+            default_missing_value = "true",
+            default_value_t = false,
+            help = "If set the output will be displayed in a pager"
+        )]
         pager: bool,
     },
 
@@ -70,8 +556,186 @@ pub enum Command {
         #[clap(
             short,
             long,
-            help = "Cancel the watch when all services are operational"
+            action = ArgAction::Set,
+            num_args = 0..=1,
+            require_equals = true,
+            default_missing_value = "true",
+            default_value_t = false,
+            help = "Cancel the watch when all services, or the selected components, are operational"
         )]
         cancel_when_operational: bool,
+
+        #[clap(
+            long,
+            action = ArgAction::Set,
+            num_args = 0..=1,
+            require_equals = true,
+            default_missing_value = "true",
+            default_value_t = false,
+            help = "After the first check only print what changed since the previous check"
+        )]
+        changes: bool,
+
+        #[clap(
+            long,
+            action = ArgAction::Set,
+            num_args = 0..=1,
+            require_equals = true,
+            default_missing_value = "true",
+            default_value_t = false,
+            help = "Record each check in the history archive"
+        )]
+        record: bool,
+
+        #[clap(
+            long,
+            help = "A shell command to run when anything changes. The old and new status are passed as JSON on stdin"
+        )]
+        on_change: Option<String>,
+
+        #[clap(
+            long,
+            help = "A shell command to run when the selected components stop being operational"
+        )]
+        on_degraded: Option<String>,
+
+        #[clap(
+            long,
+            help = "A shell command to run when the selected components are operational again"
+        )]
+        on_recovered: Option<String>,
+
+        #[clap(long, help = "A URL to post each change to as JSON")]
+        webhook: Option<String>,
+
+        #[clap(
+            long,
+            value_enum,
+            default_value_t = WebhookFormat::Generic,
+            help = "The format of the webhook payload"
+        )]
+        webhook_format: WebhookFormat,
+
+        #[clap(
+            long,
+            default_value_t = 3,
+            help = "The number of times to retry posting to the webhook if it fails"
+        )]
+        webhook_retries: u32,
+
+        #[clap(
+            long = "component",
+            help = "Only include components matching the name or glob pattern, e.g. \"Git*\". Can be passed multiple times"
+        )]
+        components: Vec<String>,
+
+        #[clap(
+            long,
+            env = "GITHUB_STATUS_PROVIDERS",
+            conflicts_with = "components",
+            help = "A TOML file listing several Statuspage providers to check together"
+        )]
+        providers: Option<PathBuf>,
     },
+
+    /// Wait quietly until all services, or the selected components, are operational
+    Wait {
+        #[clap(
+            short,
+            long,
+            default_value_t = 1,
+            help = "The duration to wait between polling in minutes"
+        )]
+        duration: u64,
+
+        #[clap(
+            short,
+            long,
+            help = "The maximum number of minutes to wait before giving up"
+        )]
+        timeout: Option<u64>,
+
+        #[clap(
+            long = "component",
+            help = "Only include components matching the name or glob pattern, e.g. \"Git*\". Can be passed multiple times"
+        )]
+        components: Vec<String>,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Report {
+    /// Availability, incident counts, and time to resolution over a period.
+    Uptime {
+        #[clap(
+            long,
+            default_value = "30d",
+            value_parser = parse_period,
+            help = "The period to report on, e.g. 30d, 12h, or 2w"
+        )]
+        since: TimeDelta,
+
+        #[clap(
+            short,
+            long,
+            action = ArgAction::Set,
+            num_args = 0..=1,
+            require_equals = true,
+            default_missing_value = "true",
+            default_value_t = false,
+            help = "If set the output will be displayed in a pager"
+        )]
+        pager: bool,
+
+        #[clap(
+            long = "component",
+            help = "Only include incidents affecting components matching the name or glob pattern, e.g. \"Git*\". Can be passed multiple times"
+        )]
+        components: Vec<String>,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, NaiveTime, TimeDelta, TimeZone, Utc};
+
+    use super::{parse_period, parse_time, TimeArg};
+
+    #[test]
+    fn test_parse_period() {
+        assert_eq!(parse_period("30d"), Ok(TimeDelta::days(30)));
+        assert_eq!(parse_period("12h"), Ok(TimeDelta::hours(12)));
+        assert_eq!(parse_period("90m"), Ok(TimeDelta::minutes(90)));
+        assert_eq!(parse_period("2w"), Ok(TimeDelta::weeks(2)));
+        assert!(parse_period("0d").is_err());
+        assert!(parse_period("30").is_err());
+        assert!(parse_period("d").is_err());
+        assert!(parse_period("").is_err());
+        assert!(parse_period("-1d").is_err());
+    }
+
+    #[test]
+    fn test_parse_time() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 10).unwrap();
+        let time = NaiveTime::from_hms_opt(3, 12, 0).unwrap();
+
+        assert_eq!(
+            parse_time("2024-01-10T03:12:00+01:00")
+                .unwrap()
+                .resolve(None),
+            Ok(Utc.from_utc_datetime(&date.and_hms_opt(2, 12, 0).unwrap()))
+        );
+        assert_eq!(
+            parse_time("2024-01-10 03:12"),
+            Ok(TimeArg::DateTime(date.and_time(time)))
+        );
+        assert_eq!(
+            parse_time("2024-01-10 03:12")
+                .unwrap()
+                .resolve(Some(chrono_tz::America::New_York)),
+            Ok(Utc.from_utc_datetime(&date.and_hms_opt(8, 12, 0).unwrap()))
+        );
+        assert_eq!(parse_time("03:12"), Ok(TimeArg::Time(time)));
+        assert!(parse_time("yesterday").is_err());
+    }
 }
@@ -0,0 +1,1889 @@
+use std::{
+    io::{self, Write},
+    thread,
+    time::Duration,
+};
+
+use anyhow::Result;
+use chrono::{DateTime, FixedOffset, Local, TimeDelta, Utc};
+use chrono_tz::Tz;
+use colored::*;
+use github_status::{
+    diff, Archive, Change, Component, ComponentFilter, ComponentInfo, ComponentNode,
+    ComponentStatus, History, Impact, Incident, IncidentInfo, IncidentStatus, Indicator,
+    MaintenanceInfo, ScheduledMaintenance, StatusClient, StatusInfo, SummaryInfo, UptimeReport,
+};
+use pager::Pager;
+use serde::Serialize;
+
+use crate::hooks::Hooks;
+use crate::options::OutputFormat;
+use crate::table::{Cell, Table};
+use crate::webhook::Webhook;
+
+const TIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S %Z";
+
+const ORANGE: Color = Color::TrueColor {
+    r: 255,
+    g: 165,
+    b: 0,
+};
+
+pub trait Print: Serialize {
+    /// Writes the human readable view to `out`.
+    fn render(&self, out: &mut dyn Write, style: &Style) -> io::Result<()>;
+
+    /// Writes the view as aligned columns that fit in `style.width`.
+    fn render_table(&self, out: &mut dyn Write, style: &Style) -> io::Result<()>;
+
+    fn render_json(&self, out: &mut dyn Write) -> Result<()> {
+        serde_json::to_writer_pretty(&mut *out, self)?;
+        writeln!(out)?;
+
+        Ok(())
+    }
+
+    fn output(&self, pager: bool, format: OutputFormat, style: &Style) -> Result<()> {
+        if pager {
+            Pager::new().setup();
+        }
+
+        let mut out = io::stdout().lock();
+        match format {
+            OutputFormat::Text => self.render(&mut out, style)?,
+            OutputFormat::Table => self.render_table(&mut out, style)?,
+            OutputFormat::Json => self.render_json(&mut out)?,
+        }
+
+        Ok(())
+    }
+}
+
+/// How text output is rendered.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Style {
+    pub time: TimeFormat,
+
+    /// Color statuses with ANSI escape codes.
+    pub color: bool,
+
+    /// The width of the terminal tables are fit to. If not set tables aren't wrapped.
+    pub width: Option<usize>,
+}
+
+impl Style {
+    /// Colors `text` if coloring is enabled and there is a color, otherwise leaves it in the
+    /// terminal's default color.
+    pub fn paint(&self, text: &str, color: Option<Color>) -> String {
+        match color {
+            Some(color) if self.color => format!("\x1b[{}m{text}\x1b[0m", color.to_fg_str()),
+            _ => text.to_string(),
+        }
+    }
+}
+
+/// How timestamps are displayed.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TimeFormat {
+    /// The time zone to display times in. If not set the local time zone is used.
+    pub tz: Option<Tz>,
+
+    /// Display times relative to now, e.g. "12 minutes ago", instead of as dates.
+    pub relative: bool,
+}
+
+impl TimeFormat {
+    /// The same format, but always displaying dates instead of relative times.
+    pub fn absolute(&self) -> TimeFormat {
+        TimeFormat {
+            relative: false,
+            ..*self
+        }
+    }
+
+    pub fn format(&self, time: &DateTime<FixedOffset>) -> String {
+        self.format_at(time, Utc::now())
+    }
+
+    fn format_at(&self, time: &DateTime<FixedOffset>, now: DateTime<Utc>) -> String {
+        if self.relative {
+            return relative(now.signed_duration_since(time));
+        }
+
+        match self.tz {
+            Some(tz) => time.with_timezone(&tz).format(TIME_FORMAT).to_string(),
+            None => time.with_timezone(&Local).format(TIME_FORMAT).to_string(),
+        }
+    }
+}
+
+/// Formats the time since an event, or until it for events in the future.
+fn relative(delta: TimeDelta) -> String {
+    let seconds = delta.num_seconds().abs();
+    let (count, unit) = if seconds < 60 {
+        return "just now".to_string();
+    } else if seconds < 60 * 60 {
+        (seconds / 60, "minute")
+    } else if seconds < 60 * 60 * 24 {
+        (seconds / (60 * 60), "hour")
+    } else {
+        (seconds / (60 * 60 * 24), "day")
+    };
+    let plural = if count == 1 { "" } else { "s" };
+
+    if delta < TimeDelta::zero() {
+        format!("in {count} {unit}{plural}")
+    } else {
+        format!("{count} {unit}{plural} ago")
+    }
+}
+
+/// The color used to display a status value.
+pub(crate) trait StatusColor {
+    fn color(&self) -> Option<Color>;
+}
+
+impl StatusColor for ComponentStatus {
+    fn color(&self) -> Option<Color> {
+        match self {
+            ComponentStatus::Operational => Some(Color::Green),
+            ComponentStatus::DegradedPerformance => Some(Color::Yellow),
+            ComponentStatus::PartialOutage => Some(ORANGE),
+            ComponentStatus::MajorOutage => Some(Color::Red),
+            ComponentStatus::UnderMaintenance => Some(Color::Blue),
+            ComponentStatus::Unknown(_) => None,
+        }
+    }
+}
+
+impl StatusColor for Impact {
+    fn color(&self) -> Option<Color> {
+        match self {
+            Impact::None => Some(Color::Green),
+            Impact::Minor => Some(Color::Yellow),
+            Impact::Major => Some(ORANGE),
+            Impact::Critical => Some(Color::Red),
+            Impact::Maintenance => Some(Color::Blue),
+            Impact::Unknown(_) => None,
+        }
+    }
+}
+
+impl StatusColor for Indicator {
+    fn color(&self) -> Option<Color> {
+        match self {
+            Indicator::None => Some(Color::Green),
+            Indicator::Minor => Some(Color::Yellow),
+            Indicator::Major => Some(ORANGE),
+            Indicator::Critical => Some(Color::Red),
+            Indicator::Unknown(_) => None,
+        }
+    }
+}
+
+/// Writes the component's status, indented by `level` levels.
+fn print_component(
+    out: &mut dyn Write,
+    style: &Style,
+    component: &Component,
+    level: usize,
+) -> io::Result<()> {
+    writeln!(
+        out,
+        "{}{}: {}",
+        "    ".repeat(level),
+        component.name,
+        style.paint(component.status.label(), component.status.color())
+    )
+}
+
+/// Formats an optional time for a table, leaving the cell empty if it isn't set.
+fn time_cell(style: &Style, time: &Option<DateTime<FixedOffset>>) -> Cell {
+    time.as_ref()
+        .map(|t| style.time.format(t))
+        .unwrap_or_default()
+        .into()
+}
+
+/// A table of the showcased components, with the components in a group indented under it.
+fn component_table(nodes: &[ComponentNode], style: &Style) -> Table {
+    let mut table = Table::new(vec!["Name", "Status", "Updated"], 0);
+    for node in nodes.iter().filter(|n| n.component.showcase) {
+        let children = node.children.iter().filter(|c| c.showcase);
+        for (indent, component) in
+            std::iter::once(("", node.component)).chain(children.map(|c| ("  ", *c)))
+        {
+            table.row(vec![
+                format!("{indent}{}", component.name).into(),
+                Cell::colored(component.status.label(), component.status.color()),
+                time_cell(style, &component.updated_at),
+            ]);
+        }
+    }
+
+    table
+}
+
+/// A table of incidents along with their most recent update.
+fn incident_table(incidents: &[Incident], style: &Style) -> Table {
+    let mut table = Table::new(
+        vec!["Name", "Impact", "Status", "Updated", "Latest Update"],
+        4,
+    );
+    for incident in incidents {
+        let latest = incident
+            .timeline()
+            .last()
+            .map(|u| u.body.as_str())
+            .unwrap_or_default();
+        table.row(vec![
+            Cell::colored(&incident.name, incident.impact.color()),
+            incident.impact.as_str().into(),
+            incident.status.as_str().into(),
+            time_cell(style, &incident.updated_at),
+            latest.into(),
+        ]);
+    }
+
+    table
+}
+
+/// A table of scheduled maintenances.
+fn maintenance_table(maintenances: &[ScheduledMaintenance], style: &Style) -> Table {
+    let mut table = Table::new(vec!["Name", "Status", "Updated", "Short Link"], 0);
+    for maintenance in maintenances {
+        table.row(vec![
+            Cell::colored(&maintenance.name, maintenance.impact.color()),
+            maintenance.status.as_str().into(),
+            time_cell(style, &maintenance.updated_at),
+            maintenance.shortlink.as_str().into(),
+        ]);
+    }
+
+    table
+}
+
+impl Print for ComponentInfo {
+    fn render(&self, out: &mut dyn Write, style: &Style) -> io::Result<()> {
+        for node in self.tree().iter().filter(|n| n.component.showcase) {
+            print_component(out, style, node.component, 0)?;
+            if let Some(updated_at) = &node.component.updated_at {
+                writeln!(
+                    out,
+                    "    Last Updated At: {}",
+                    style.time.format(updated_at)
+                )?;
+            }
+
+            for child in node.children.iter().filter(|c| c.showcase) {
+                print_component(out, style, child, 1)?;
+                if let Some(updated_at) = &child.updated_at {
+                    writeln!(
+                        out,
+                        "        Last Updated At: {}",
+                        style.time.format(updated_at)
+                    )?;
+                }
+            }
+
+            writeln!(out)?;
+        }
+
+        writeln!(out, "More info: {:?}", self.page.url)?;
+
+        Ok(())
+    }
+
+    fn render_table(&self, out: &mut dyn Write, style: &Style) -> io::Result<()> {
+        component_table(&self.tree(), style).render(out, style)?;
+
+        writeln!(out)?;
+        writeln!(out, "More info: {}", self.page.url)
+    }
+}
+
+impl Print for MaintenanceInfo {
+    fn render(&self, out: &mut dyn Write, style: &Style) -> io::Result<()> {
+        if self.scheduled_maintenances.is_empty() {
+            writeln!(out, "No unresolved incidents reported")?;
+            writeln!(out)?;
+        } else {
+            for incident in &self.scheduled_maintenances {
+                writeln!(
+                    out,
+                    "{}",
+                    style.paint(&incident.name, incident.impact.color())
+                )?;
+
+                if let Some(created_at) = &incident.created_at {
+                    writeln!(out, "    Created At: {}", style.time.format(created_at))?;
+                }
+                writeln!(out, "    Short Link: {}", incident.shortlink)?;
+                writeln!(out, "    Status: {}", incident.status)?;
+
+                if let Some(updated_at) = &incident.updated_at {
+                    writeln!(out, "    Updated At: {}", style.time.format(updated_at))?;
+                }
+                if let Some(incident_updates) = &incident.incident_updates {
+                    writeln!(out, "    Updates:")?;
+                    for update in incident_updates {
+                        writeln!(out, "        Update: {}", update.body)?;
+                        if let Some(created_at) = &update.created_at {
+                            writeln!(out, "        created_at: {}", style.time.format(created_at))?;
+                        }
+                        writeln!(out, "        status: {}", update.status)?;
+                        if let Some(updated_at) = &update.updated_at {
+                            writeln!(out, "        Updated At: {}", style.time.format(updated_at))?;
+                        }
+                    }
+                }
+
+                writeln!(out)?;
+            }
+        }
+
+        if let Some(updated_at) = &self.page.updated_at {
+            writeln!(out, "Last update: {}", style.time.format(updated_at))?;
+        }
+        writeln!(out, "More info: {}", self.page.url)?;
+
+        Ok(())
+    }
+
+    fn render_table(&self, out: &mut dyn Write, style: &Style) -> io::Result<()> {
+        if self.scheduled_maintenances.is_empty() {
+            writeln!(out, "No unresolved incidents reported")?;
+        } else {
+            maintenance_table(&self.scheduled_maintenances, style).render(out, style)?;
+        }
+
+        writeln!(out)?;
+        if let Some(updated_at) = &self.page.updated_at {
+            writeln!(out, "Last update: {}", style.time.format(updated_at))?;
+        }
+        writeln!(out, "More info: {}", self.page.url)
+    }
+}
+
+impl Print for StatusInfo {
+    fn render(&self, out: &mut dyn Write, style: &Style) -> io::Result<()> {
+        writeln!(
+            out,
+            "{}",
+            style.paint(&self.status.description, self.status.indicator.color())
+        )?;
+
+        writeln!(out)?;
+        if let Some(updated_at) = &self.page.updated_at {
+            writeln!(out, "Last update: {}", style.time.format(updated_at))?;
+        }
+        writeln!(out, "More info: {}", self.page.url)?;
+
+        Ok(())
+    }
+
+    fn render_table(&self, out: &mut dyn Write, style: &Style) -> io::Result<()> {
+        let mut table = Table::new(vec!["Status", "Indicator", "Updated"], 0);
+        table.row(vec![
+            Cell::colored(&self.status.description, self.status.indicator.color()),
+            Cell::colored(
+                self.status.indicator.as_str(),
+                self.status.indicator.color(),
+            ),
+            time_cell(style, &self.page.updated_at),
+        ]);
+        table.render(out, style)?;
+
+        writeln!(out)?;
+        writeln!(out, "More info: {}", self.page.url)
+    }
+}
+
+impl Print for SummaryInfo {
+    fn render(&self, out: &mut dyn Write, style: &Style) -> io::Result<()> {
+        writeln!(
+            out,
+            "{}",
+            style.paint(&self.status.description, self.status.indicator.color())
+        )?;
+
+        writeln!(out)?;
+
+        for node in self.tree().iter().filter(|n| n.component.showcase) {
+            print_component(out, style, node.component, 0)?;
+            for child in node.children.iter().filter(|c| c.showcase) {
+                print_component(out, style, child, 1)?;
+            }
+        }
+
+        writeln!(out)?;
+        if let Some(updated_at) = &self.page.updated_at {
+            writeln!(out, "Last Updated At: {}", style.time.format(updated_at))?;
+        }
+        writeln!(out, "More info: {}", self.page.url)?;
+
+        Ok(())
+    }
+
+    fn render_table(&self, out: &mut dyn Write, style: &Style) -> io::Result<()> {
+        writeln!(
+            out,
+            "{}",
+            style.paint(&self.status.description, self.status.indicator.color())
+        )?;
+
+        writeln!(out)?;
+        component_table(&self.tree(), style).render(out, style)?;
+
+        if !self.incidents.is_empty() {
+            writeln!(out)?;
+            incident_table(&self.incidents, style).render(out, style)?;
+        }
+        if !self.scheduled_maintenances.is_empty() {
+            writeln!(out)?;
+            maintenance_table(&self.scheduled_maintenances, style).render(out, style)?;
+        }
+
+        writeln!(out)?;
+        if let Some(updated_at) = &self.page.updated_at {
+            writeln!(out, "Last Updated At: {}", style.time.format(updated_at))?;
+        }
+        writeln!(out, "More info: {}", self.page.url)
+    }
+}
+
+/// Formats a duration such as the time to resolve an incident, e.g. "1d 2h 13m".
+fn format_duration(delta: TimeDelta) -> String {
+    let minutes = delta.num_minutes().max(0);
+    let (days, hours, minutes) = (minutes / (60 * 24), minutes / 60 % 24, minutes % 60);
+
+    if days > 0 {
+        format!("{days}d {hours}h {minutes}m")
+    } else if hours > 0 {
+        format!("{hours}h {minutes}m")
+    } else {
+        format!("{minutes}m")
+    }
+}
+
+/// Writes an incident's name followed by its impact, status, and times.
+fn print_incident_details(
+    out: &mut dyn Write,
+    style: &Style,
+    incident: &Incident,
+) -> io::Result<()> {
+    writeln!(
+        out,
+        "{}",
+        style.paint(&incident.name, incident.impact.color())
+    )?;
+    writeln!(out, "    Impact: {}", incident.impact)?;
+    writeln!(out, "    Status: {}", incident.status)?;
+    writeln!(out, "    Short Link: {}", incident.shortlink)?;
+
+    if !incident.components.is_empty() {
+        let names: Vec<&str> = incident
+            .components
+            .iter()
+            .map(|c| c.name.as_str())
+            .collect();
+        writeln!(out, "    Affected Components: {}", names.join(", "))?;
+    }
+    if let Some(created_at) = &incident.created_at {
+        writeln!(out, "    Created At: {}", style.time.format(created_at))?;
+    }
+    if let Some(resolved_at) = &incident.resolved_at {
+        writeln!(out, "    Resolved At: {}", style.time.format(resolved_at))?;
+    }
+    if let Some(time_to_resolution) = incident.time_to_resolution() {
+        writeln!(
+            out,
+            "    Time To Resolution: {}",
+            format_duration(time_to_resolution)
+        )?;
+    }
+
+    Ok(())
+}
+
+impl Print for Incident {
+    fn render(&self, out: &mut dyn Write, style: &Style) -> io::Result<()> {
+        print_incident_details(out, style, self)?;
+
+        writeln!(out)?;
+        writeln!(out, "Timeline:")?;
+
+        let mut previous: Option<&IncidentStatus> = None;
+        for update in self.timeline() {
+            let status = match previous {
+                Some(previous) if previous != &update.status => {
+                    format!("{previous} -> {}", update.status)
+                }
+                _ => update.status.to_string(),
+            };
+            match &update.created_at {
+                Some(created_at) => {
+                    writeln!(out, "    {}: {status}", style.time.format(created_at))?
+                }
+                None => writeln!(out, "    {status}")?,
+            }
+
+            writeln!(out, "        {}", update.body)?;
+
+            for component in update.affected_components.iter().flatten() {
+                writeln!(
+                    out,
+                    "        {}: {} -> {}",
+                    component.name,
+                    style.paint(component.old_status.label(), component.old_status.color()),
+                    style.paint(component.new_status.label(), component.new_status.color())
+                )?;
+            }
+
+            writeln!(out)?;
+            previous = Some(&update.status);
+        }
+
+        Ok(())
+    }
+
+    fn render_table(&self, out: &mut dyn Write, style: &Style) -> io::Result<()> {
+        print_incident_details(out, style, self)?;
+        writeln!(out)?;
+
+        let mut table = Table::new(vec!["Time", "Status", "Affected", "Update"], 3);
+        for update in self.timeline() {
+            let affected: Vec<String> = update
+                .affected_components
+                .iter()
+                .flatten()
+                .map(|c| format!("{}: {}", c.name, c.new_status.label()))
+                .collect();
+
+            table.row(vec![
+                time_cell(style, &update.created_at),
+                update.status.as_str().into(),
+                affected.join(", ").into(),
+                update.body.as_str().into(),
+            ]);
+        }
+
+        table.render(out, style)
+    }
+}
+
+impl Print for IncidentInfo {
+    fn render(&self, out: &mut dyn Write, style: &Style) -> io::Result<()> {
+        if self.incidents.is_empty() {
+            writeln!(out, "No unresolved incidents reported")?;
+            writeln!(out)?;
+        } else {
+            for incident in &self.incidents {
+                writeln!(
+                    out,
+                    "{}",
+                    style.paint(&incident.name, incident.impact.color())
+                )?;
+
+                if let Some(created_at) = &incident.created_at {
+                    writeln!(out, "    Created At: {}", style.time.format(created_at))?;
+                }
+                writeln!(out, "    Short Link: {}", incident.shortlink)?;
+                writeln!(out, "    Status: {}", incident.status)?;
+
+                if let Some(updated_at) = &incident.updated_at {
+                    writeln!(out, "    Updated At: {}", style.time.format(updated_at))?;
+                }
+                if let Some(incident_updates) = &incident.incident_updates {
+                    writeln!(out, "    Updates:")?;
+                    for update in incident_updates {
+                        writeln!(out, "        Update: {}", update.body)?;
+                        if let Some(created_at) = &update.created_at {
+                            writeln!(out, "        created_at: {}", style.time.format(created_at))?;
+                        }
+                        writeln!(out, "        status: {}", update.status)?;
+                        if let Some(updated_at) = &update.updated_at {
+                            writeln!(out, "        Updated At: {}", style.time.format(updated_at))?;
+                        }
+                    }
+                }
+
+                writeln!(out)?;
+            }
+        }
+
+        if let Some(updated_at) = &self.page.updated_at {
+            writeln!(out, "Last update: {}", style.time.format(updated_at))?;
+        }
+        writeln!(out, "More info: {}", self.page.url)?;
+
+        Ok(())
+    }
+
+    fn render_table(&self, out: &mut dyn Write, style: &Style) -> io::Result<()> {
+        if self.incidents.is_empty() {
+            writeln!(out, "No unresolved incidents reported")?;
+        } else {
+            incident_table(&self.incidents, style).render(out, style)?;
+        }
+
+        writeln!(out)?;
+        if let Some(updated_at) = &self.page.updated_at {
+            writeln!(out, "Last update: {}", style.time.format(updated_at))?;
+        }
+        writeln!(out, "More info: {}", self.page.url)
+    }
+}
+
+/// Formats an availability percentage with three decimals, e.g. "99.950%".
+fn format_availability(availability: f64) -> String {
+    format!("{availability:.3}%")
+}
+
+/// Writes the period and overall numbers of an uptime report.
+fn print_uptime_overview(
+    out: &mut dyn Write,
+    style: &Style,
+    report: &UptimeReport,
+) -> io::Result<()> {
+    let time = style.time.absolute();
+    writeln!(
+        out,
+        "Uptime from {} to {}",
+        time.format(&report.since.fixed_offset()),
+        time.format(&report.until.fixed_offset())
+    )?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "Availability: {}",
+        format_availability(report.availability)
+    )?;
+    writeln!(
+        out,
+        "Degraded: {}",
+        format_duration(TimeDelta::minutes(report.degraded_minutes))
+    )?;
+
+    let total: usize = report.incidents.values().sum();
+    let counts: Vec<String> = report
+        .incidents
+        .iter()
+        .map(|(impact, count)| format!("{impact}: {count}"))
+        .collect();
+    if counts.is_empty() {
+        writeln!(out, "Incidents: 0")?;
+    } else {
+        writeln!(out, "Incidents: {total} ({})", counts.join(", "))?;
+    }
+
+    if let Some(minutes) = report.mean_time_to_resolution_minutes {
+        writeln!(
+            out,
+            "Mean Time To Resolution: {}",
+            format_duration(TimeDelta::minutes(minutes))
+        )?;
+    }
+    if report.archived_minutes > 0 {
+        writeln!(
+            out,
+            "Recorded History: {}",
+            format_duration(TimeDelta::minutes(report.archived_minutes))
+        )?;
+    }
+
+    Ok(())
+}
+
+impl Print for UptimeReport {
+    fn render(&self, out: &mut dyn Write, style: &Style) -> io::Result<()> {
+        print_uptime_overview(out, style, self)?;
+
+        if !self.components.is_empty() {
+            writeln!(out)?;
+            writeln!(out, "Components:")?;
+            for component in &self.components {
+                let plural = if component.incidents == 1 { "" } else { "s" };
+                writeln!(
+                    out,
+                    "    {}: {} ({} degraded, {} incident{plural})",
+                    component.name,
+                    format_availability(component.availability),
+                    format_duration(TimeDelta::minutes(component.degraded_minutes)),
+                    component.incidents
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn render_table(&self, out: &mut dyn Write, style: &Style) -> io::Result<()> {
+        print_uptime_overview(out, style, self)?;
+
+        if !self.components.is_empty() {
+            writeln!(out)?;
+            let mut table = Table::new(
+                vec!["Component", "Availability", "Degraded", "Incidents"],
+                0,
+            );
+            for component in &self.components {
+                table.row(vec![
+                    component.name.as_str().into(),
+                    format_availability(component.availability).into(),
+                    format_duration(TimeDelta::minutes(component.degraded_minutes)).into(),
+                    component.incidents.to_string().into(),
+                ]);
+            }
+            table.render(out, style)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Print for History {
+    fn render(&self, out: &mut dyn Write, style: &Style) -> io::Result<()> {
+        if self.entries.is_empty() {
+            return writeln!(out, "No history was recorded for this period");
+        }
+
+        let time = style.time.absolute();
+        for (i, entry) in self.entries.iter().enumerate() {
+            if i > 0 {
+                writeln!(out)?;
+            }
+            writeln!(
+                out,
+                "{} to {}: {}",
+                time.format(&entry.from.fixed_offset()),
+                time.format(&entry.until.fixed_offset()),
+                style.paint(&entry.description, entry.indicator.color())
+            )?;
+            for component in &entry.components {
+                writeln!(
+                    out,
+                    "    {}: {}",
+                    component.name,
+                    style.paint(component.status.label(), component.status.color())
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn render_table(&self, out: &mut dyn Write, style: &Style) -> io::Result<()> {
+        if self.entries.is_empty() {
+            return writeln!(out, "No history was recorded for this period");
+        }
+
+        let time = style.time.absolute();
+        let mut table = Table::new(vec!["From", "Until", "Status", "Components"], 3);
+        for entry in &self.entries {
+            let components: Vec<String> = entry
+                .components
+                .iter()
+                .map(|c| format!("{}: {}", c.name, c.status.label()))
+                .collect();
+
+            table.row(vec![
+                time.format(&entry.from.fixed_offset()).into(),
+                time.format(&entry.until.fixed_offset()).into(),
+                Cell::colored(&entry.description, entry.indicator.color()),
+                components.join(", ").into(),
+            ]);
+        }
+
+        table.render(out, style)
+    }
+}
+
+/// Options for the `watch` command.
+#[derive(Debug)]
+pub struct WatchOptions {
+    /// The duration to wait between polls.
+    pub duration: Duration,
+
+    /// Stop once the selected components are operational.
+    pub cancel_when_operational: bool,
+
+    /// After the first poll only print the changes since the previous poll.
+    pub changes_only: bool,
+
+    /// Commands to run when the status changes.
+    pub hooks: Hooks,
+
+    /// Where to post each change.
+    pub webhook: Option<Webhook>,
+
+    /// Where to record each check, if set.
+    pub archive: Option<Archive>,
+
+    /// Print the causes of failed polls.
+    pub verbose: bool,
+}
+
+/// Prints an error to stderr, along with its causes if `verbose` is set.
+pub fn print_error(e: &anyhow::Error, verbose: bool) {
+    eprintln!("{}", format!("Error: {e}").red());
+    if verbose {
+        for cause in e.chain().skip(1) {
+            eprintln!("{}", format!("  Caused by: {cause}").red());
+        }
+    }
+}
+
+/// Writes a change detected by `watch` after `prefix`, e.g. the time it was detected.
+fn print_change(
+    out: &mut dyn Write,
+    style: &Style,
+    change: &Change,
+    prefix: &str,
+) -> io::Result<()> {
+    match change {
+        Change::Indicator {
+            new, description, ..
+        } => writeln!(
+            out,
+            "{prefix}Status: {}",
+            style.paint(description, new.color())
+        ),
+        Change::Component { name, old, new } => writeln!(
+            out,
+            "{prefix}{name}: {} -> {}",
+            style.paint(old.label(), old.color()),
+            style.paint(new.label(), new.color())
+        ),
+        Change::IncidentOpened {
+            name,
+            impact,
+            status,
+            shortlink,
+        } => writeln!(
+            out,
+            "{prefix}Incident opened: {} ({status}) {shortlink}",
+            style.paint(name, impact.color())
+        ),
+        Change::IncidentUpdated {
+            name,
+            old,
+            new,
+            shortlink,
+        } => writeln!(
+            out,
+            "{prefix}Incident updated: {name}: {old} -> {new} {shortlink}"
+        ),
+        Change::IncidentResolved { name, shortlink } => writeln!(
+            out,
+            "{prefix}Incident resolved: {} {shortlink}",
+            style.paint(name, Some(Color::Green))
+        ),
+    }
+}
+
+/// A change along with when `watch` detected it, used when changes are sent as JSON.
+#[derive(Serialize)]
+pub struct DetectedChange<'a> {
+    pub detected_at: DateTime<Utc>,
+
+    /// The name of the provider whose status page changed, when watching several.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider: Option<&'a str>,
+
+    #[serde(flatten)]
+    pub change: &'a Change,
+}
+
+impl DetectedChange<'_> {
+    /// A plain text description of the change, prefixed with the provider if there is one.
+    pub fn text(&self) -> String {
+        match self.provider {
+            Some(provider) => format!("{provider}: {}", self.change),
+            None => self.change.to_string(),
+        }
+    }
+}
+
+/// A status page polled by `watch`.
+#[derive(Debug)]
+pub struct WatchTarget {
+    /// The provider's name, used to label its output when watching several.
+    pub name: Option<String>,
+
+    pub client: StatusClient,
+
+    pub filter: ComponentFilter,
+}
+
+/// A summary printed by `watch` as JSON, labeled with its provider when watching several.
+#[derive(Serialize)]
+struct Snapshot<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    provider: Option<&'a str>,
+
+    #[serde(flatten)]
+    summary: &'a SummaryInfo,
+}
+
+pub fn watch(
+    targets: &[WatchTarget],
+    options: &WatchOptions,
+    format: OutputFormat,
+    style: &Style,
+    out: &mut dyn Write,
+) -> Result<()> {
+    let WatchOptions {
+        duration,
+        cancel_when_operational,
+        changes_only,
+        hooks,
+        webhook,
+        archive,
+        verbose,
+    } = options;
+    let mut check = 1;
+    let mut recorders: Vec<_> = targets
+        .iter()
+        .map(|t| archive.as_ref().map(|a| a.recorder(t.client.base_url())))
+        .collect();
+    // The previous summary of each target, and whether its components were operational.
+    let mut previous: Vec<Option<(SummaryInfo, bool)>> = targets.iter().map(|_| None).collect();
+
+    if format != OutputFormat::Json {
+        let names: Vec<&str> = targets.iter().filter_map(|t| t.name.as_deref()).collect();
+        let watching = if names.is_empty() {
+            "GitHub status".to_string()
+        } else {
+            names.join(", ")
+        };
+
+        if *cancel_when_operational {
+            writeln!(
+                out,
+                "Watching {watching} with {duration:?} between checks. Polling will stop when everything is operational. Press Ctrl + c to cancel early."
+            )?;
+        } else {
+            writeln!(
+                out,
+                "Watching {watching} with {duration:?} between checks. Press Ctrl + c to cancel."
+            )?
+        };
+    }
+
+    loop {
+        let full_check = !*changes_only || previous.iter().any(Option::is_none);
+        if format != OutputFormat::Json && full_check {
+            writeln!(out, "\nCheck number: {check}\n")?;
+        }
+
+        let mut all_operational = true;
+
+        for ((target, previous), recorder) in
+            targets.iter().zip(previous.iter_mut()).zip(&mut recorders)
+        {
+            let WatchTarget {
+                name,
+                client,
+                filter,
+            } = target;
+            let name = name.as_deref();
+
+            let mut s = match client.summary() {
+                Ok(s) => s,
+                Err(e) => {
+                    print_error(&e, *verbose);
+                    all_operational = false;
+                    continue;
+                }
+            };
+
+            // The whole summary is recorded so the history can be filtered later.
+            if let Some(recorder) = recorder {
+                if let Err(e) = recorder.record(&s, Utc::now()) {
+                    eprintln!("{}", format!("Unable to record the status: {e}").red());
+                }
+            }
+
+            // A pattern that stops matching, e.g. after a component is renamed, is reported like a
+            // failed poll instead of ending the watch.
+            let operational = match s.retain(filter).and_then(|_| s.is_operational(filter)) {
+                Ok(operational) => operational,
+                Err(e) => {
+                    print_error(&e, *verbose);
+                    all_operational = false;
+                    continue;
+                }
+            };
+
+            let changes = previous
+                .as_ref()
+                .map(|(previous, _)| diff(previous, &s))
+                .unwrap_or_default();
+
+            if let Some((previous, was_operational)) = previous.as_ref() {
+                if !hooks.is_empty() {
+                    hooks.run(name, previous, &s, &changes, *was_operational, operational);
+                }
+            }
+
+            let detected_at = Utc::now();
+            let changes: Vec<DetectedChange> = changes
+                .iter()
+                .map(|change| DetectedChange {
+                    detected_at,
+                    provider: name,
+                    change,
+                })
+                .collect();
+
+            if let Some(webhook) = webhook {
+                for change in &changes {
+                    if let Err(e) = webhook.send(change) {
+                        eprintln!("{}", e.to_string().red());
+                    }
+                }
+            }
+
+            match (&previous, changes_only) {
+                (Some(_), true) => {
+                    for change in &changes {
+                        match format {
+                            OutputFormat::Text | OutputFormat::Table => {
+                                let time =
+                                    style.time.absolute().format(&detected_at.fixed_offset());
+                                let prefix = match name {
+                                    Some(name) => format!("[{time}] {name}: "),
+                                    None => format!("[{time}] "),
+                                };
+                                print_change(out, style, change.change, &prefix)?
+                            }
+                            OutputFormat::Json => {
+                                writeln!(out, "{}", serde_json::to_string(change)?)?
+                            }
+                        }
+                    }
+                }
+                _ => {
+                    if let (Some(name), true) = (name, format != OutputFormat::Json) {
+                        writeln!(out, "== {name} ==")?;
+                    }
+
+                    match format {
+                        OutputFormat::Text => s.render(out, style)?,
+                        OutputFormat::Table => s.render_table(out, style)?,
+                        // Each check is written on a single line so the output can be streamed
+                        // as JSON Lines.
+                        OutputFormat::Json => writeln!(
+                            out,
+                            "{}",
+                            serde_json::to_string(&Snapshot {
+                                provider: name,
+                                summary: &s,
+                            })?
+                        )?,
+                    }
+
+                    if name.is_some() && format != OutputFormat::Json {
+                        writeln!(out)?;
+                    }
+                }
+            }
+
+            all_operational &= operational;
+            *previous = Some((s, operational));
+        }
+
+        if *cancel_when_operational && all_operational {
+            if format != OutputFormat::Json {
+                writeln!(out, "All services are operational, exiting")?;
+            }
+            return Ok(());
+        }
+
+        out.flush()?;
+        check += 1;
+        thread::sleep(*duration);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, path::Path};
+
+    use chrono::{DateTime, TimeDelta};
+    use chrono_tz::Tz;
+    use github_status::{
+        Change, ComponentFilter, ComponentInfo, ComponentState, ComponentStatus, History,
+        HistoryEntry, Impact, Incident, IncidentInfo, IncidentStatus, Indicator, MaintenanceInfo,
+        StatusInfo, SummaryInfo, UptimeReport,
+    };
+
+    use super::{format_duration, print_change, Print, Style, TimeFormat};
+
+    fn style(color: bool) -> Style {
+        Style {
+            time: TimeFormat {
+                tz: Some(Tz::UTC),
+                relative: false,
+            },
+            color,
+            width: Some(80),
+        }
+    }
+
+    /// Renders `value` as text with times in UTC.
+    fn render(value: &impl Print, color: bool) -> String {
+        let mut out = Vec::new();
+        value.render(&mut out, &style(color)).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    /// Renders `value` as a table fit to 80 columns.
+    fn render_table(value: &impl Print) -> String {
+        let mut out = Vec::new();
+        value.render_table(&mut out, &style(false)).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    /// Compares `actual` with the golden file `tests/snapshots/{name}.txt`. Run the tests with
+    /// `UPDATE_SNAPSHOTS=1` to rewrite the golden files after an intended change.
+    fn assert_snapshot(name: &str, actual: &str) {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/snapshots")
+            .join(format!("{name}.txt"));
+
+        if env::var_os("UPDATE_SNAPSHOTS").is_some() {
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(&path, actual).unwrap();
+            return;
+        }
+
+        let expected = fs::read_to_string(&path).unwrap_or_else(|_| {
+            panic!(
+                "{} is missing, run the tests with UPDATE_SNAPSHOTS=1 to create it",
+                path.display()
+            )
+        });
+        assert_eq!(
+            actual,
+            expected,
+            "the output differs from {}",
+            path.display()
+        );
+    }
+
+    #[test]
+    fn test_time_format() {
+        let time = DateTime::parse_from_rfc3339("2014-05-14T14:22:39.441-06:00").unwrap();
+        let tz: Tz = "America/New_York".parse().unwrap();
+
+        let format = TimeFormat {
+            tz: Some(tz),
+            relative: false,
+        };
+        assert_eq!(
+            format.format_at(&time, time.to_utc()),
+            "2014-05-14 16:22:39 EDT"
+        );
+
+        let format = TimeFormat {
+            tz: None,
+            relative: true,
+        };
+        assert_eq!(format.format_at(&time, time.to_utc()), "just now");
+        assert_eq!(
+            format.format_at(&time, time.to_utc() + TimeDelta::minutes(12)),
+            "12 minutes ago"
+        );
+        assert_eq!(
+            format.format_at(&time, time.to_utc() - TimeDelta::hours(1)),
+            "in 1 hour"
+        );
+        assert_eq!(
+            format.format_at(&time, time.to_utc() + TimeDelta::days(3)),
+            "3 days ago"
+        );
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(TimeDelta::seconds(59)), "0m");
+        assert_eq!(format_duration(TimeDelta::minutes(133)), "2h 13m");
+        assert_eq!(format_duration(TimeDelta::minutes(60 * 26 + 5)), "1d 2h 5m");
+    }
+
+    #[test]
+    fn test_print_incident() {
+        let data = r#"
+            {
+              "components": [
+                {"id": "b13yz5g2cw10", "name": "API Requests", "status": "operational"}
+              ],
+              "created_at": "2014-05-14T14:22:39.441-06:00",
+              "id": "cp306tmzcl0y",
+              "impact": "major",
+              "incident_updates": [
+                {
+                  "affected_components": [
+                    {"code": "b13yz5g2cw10", "name": "API Requests", "old_status": "partial_outage", "new_status": "operational"}
+                  ],
+                  "body": "This incident has been resolved.",
+                  "created_at": "2014-05-14T16:35:21.711-06:00",
+                  "status": "resolved",
+                  "updated_at": "2014-05-14T16:35:21.711-06:00"
+                },
+                {
+                  "body": "We are investigating reports of degraded performance.",
+                  "created_at": "2014-05-14T14:22:40.301-06:00",
+                  "status": "investigating",
+                  "updated_at": "2014-05-14T14:22:40.301-06:00"
+                }
+              ],
+              "name": "Degraded performance for API Requests",
+              "resolved_at": "2014-05-14T16:35:21.711-06:00",
+              "shortlink": "https://stspg.io/abc123",
+              "status": "resolved",
+              "updated_at": "2014-05-14T16:35:21.711-06:00"
+            }"#;
+
+        let incident: Incident = serde_json::from_str(data).unwrap();
+        assert_snapshot("incident", &render(&incident, false));
+        assert_snapshot("incident_table", &render_table(&incident));
+    }
+
+    #[test]
+    fn test_print_component_info() {
+        let data = r#"
+            {
+              "page": {
+                "id": "kctbh9vrtdwd",
+                "name": "GitHub",
+                "url": "https://www.githubstatus.com",
+                "updated_at": "2022-09-05T08:07:25Z"
+              },
+              "components": [
+                {
+                  "created_at": "2014-05-03T01:22:07.274Z",
+                  "description": null,
+                  "group": false,
+                  "group_id": null,
+                  "id": "b13yz5g2cw10",
+                  "name": "API",
+                  "only_show_if_degraded": false,
+                  "page_id": "kctbh9vrtdwd",
+                  "position": 1,
+                  "showcase": true,
+                  "start_date": null,
+                  "status": "partial_outage",
+                  "updated_at": "2014-05-14T20:34:43.340Z"
+                },
+                {
+                  "created_at": "2014-05-03T01:22:07.286Z",
+                  "description": null,
+                  "group": false,
+                  "group_id": null,
+                  "id": "9397cnvk62zn",
+                  "name": "Management Portal",
+                  "only_show_if_degraded": false,
+                  "page_id": "kctbh9vrtdwd",
+                  "position": 2,
+                  "showcase": true,
+                  "start_date": null,
+                  "status": "major_outage",
+                  "updated_at": "2014-05-14T20:34:44.470Z"
+                }
+              ]
+            }"#;
+
+        let info: ComponentInfo = serde_json::from_str(data).unwrap();
+        assert_snapshot("component_info", &render(&info, false));
+        assert_snapshot("component_info_table", &render_table(&info));
+    }
+
+    #[test]
+    fn test_print_all_incident_info() {
+        let data = r#"
+            {
+              "page":{
+                "id":"kctbh9vrtdwd",
+                "name":"GitHub",
+                "url":"https://www.githubstatus.com",
+                "updated_at": "2022-09-05T08:07:25Z"
+              },
+              "incidents": [
+                {
+                  "created_at": "2014-05-14T14:22:39.441-06:00",
+                  "id": "cp306tmzcl0y",
+                  "impact": "critical",
+                  "incident_updates": [
+                    {
+                      "body": "Our master database has ham sandwiches flying out of the rack, and we're working our hardest to stop the bleeding. The whole site is down while we restore functionality, and we'll provide another update within 30 minutes.",
+                      "created_at": "2014-05-14T14:22:40.301-06:00",
+                      "display_at": "2014-05-14T14:22:40.301-06:00",
+                      "id": "jdy3tw5mt5r5",
+                      "incident_id": "cp306tmzcl0y",
+                      "status": "identified",
+                      "updated_at": "2014-05-14T14:22:40.301-06:00"
+                    }
+                  ],
+                  "monitoring_at": null,
+                  "name": "Unplanned Database Outage",
+                  "page_id": "kctbh9vrtdwd",
+                  "resolved_at": null,
+                  "shortlink": "http://stspg.co:5000/Q0E",
+                  "status": "identified",
+                  "updated_at": "2014-05-14T14:35:21.711-06:00"
+                },
+                {
+                  "created_at": "2014-05-12T14:22:39.441-06:00",
+                  "id": "2z5g29qrrxvl",
+                  "impact": "minor",
+                  "incident_updates": [
+                    {
+                      "body": "A small display issue with the display of the website was discovered after a recent deploy. The deploy has been rolled back and the website is again functioning correctly.",
+                      "created_at": "2014-05-12T14:22:40.301-06:00",
+                      "display_at": "2014-05-12T14:22:40.301-06:00",
+                      "id": "vlzc06gtjnrl",
+                      "incident_id": "2z5g29qrrxvl",
+                      "status": "resolved",
+                      "updated_at": "2014-05-12T14:22:40.301-06:00"
+                    }
+                  ],
+                  "monitoring_at": null,
+                  "name": "Unplanned Database Outage",
+                  "page_id": "kctbh9vrtdwd",
+                  "resolved_at": "2014-05-12T14:22:40.301-06:00",
+                  "shortlink": "http://stspg.co:5000/Q0R",
+                  "status": "resolved",
+                  "updated_at": "2014-05-12T14:22:40.301-06:00"
+                }
+              ]
+            }"#;
+
+        let info: IncidentInfo = serde_json::from_str(data).unwrap();
+        assert_snapshot("all_incident_info", &render(&info, false));
+        assert_snapshot("all_incident_info_table", &render_table(&info));
+    }
+
+    #[test]
+    fn test_print_unresolved_incidents() {
+        let data = r#"
+            {
+              "page":{
+                "id":"kctbh9vrtdwd",
+                "name":"GitHub",
+                "url":"https://www.githubstatus.com",
+                "updated_at": "2022-09-05T08:07:25Z"
+              },
+              "incidents": [
+                {
+                  "created_at": "2014-05-14T14:22:39.441-06:00",
+                  "id": "cp306tmzcl0y",
+                  "impact": "critical",
+                  "incident_updates": [
+                    {
+                      "body": "Our master database has ham sandwiches flying out of the rack, and we're working our hardest to stop the bleeding. The whole site is down while we restore functionality, and we'll provide another update within 30 minutes.",
+                      "created_at": "2014-05-14T14:22:40.301-06:00",
+                      "display_at": "2014-05-14T14:22:40.301-06:00",
+                      "id": "jdy3tw5mt5r5",
+                      "incident_id": "cp306tmzcl0y",
+                      "status": "identified",
+                      "updated_at": "2014-05-14T14:22:40.301-06:00"
+                    }
+                  ],
+                  "monitoring_at": null,
+                  "name": "Unplanned Database Outage",
+                  "page_id": "kctbh9vrtdwd",
+                  "resolved_at": null,
+                  "shortlink": "http://stspg.co:5000/Q0E",
+                  "status": "identified",
+                  "updated_at": "2014-05-14T14:35:21.711-06:00"
+                }
+              ]
+            }"#;
+
+        let info: IncidentInfo = serde_json::from_str(data).unwrap();
+        assert_snapshot("unresolved_incidents", &render(&info, false));
+        assert_snapshot("unresolved_incidents_table", &render_table(&info));
+    }
+
+    #[test]
+    fn test_print_activate_maintenance() {
+        let data = r#"
+            {
+              "page":{
+                "id":"kctbh9vrtdwd",
+                "name":"GitHub",
+                "url":"https://www.githubstatus.com",
+                "updated_at": "2022-09-05T08:07:25Z"
+              },
+              "scheduled_maintenances": [
+                {
+                  "created_at": "2014-05-14T14:27:17.303-06:00",
+                  "id": "k7mf5z1gz05c",
+                  "impact": "minor",
+                  "incident_updates": [
+                    {
+                      "body": "Scheduled maintenance is currently in progress. We will provide updates as necessary.",
+                      "created_at": "2014-05-14T14:34:20.036-06:00",
+                      "display_at": "2014-05-14T14:34:20.036-06:00",
+                      "id": "drs62w8df6fs",
+                      "incident_id": "k7mf5z1gz05c",
+                      "status": "in_progress",
+                      "updated_at": "2014-05-14T14:34:20.036-06:00"
+                    },
+                    {
+                      "body": "We will be performing rolling upgrades to our web tier with a new kernel version so that Heartbleed will stop making us lose sleep at night. Increased load and latency is expected, but the app should still function appropriately. We will provide updates every 30 minutes with progress of the reboots.",
+                      "created_at": "2014-05-14T14:27:18.845-06:00",
+                      "display_at": "2014-05-14T14:27:18.845-06:00",
+                      "id": "z40y7398jqxc",
+                      "incident_id": "k7mf5z1gz05c",
+                      "status": "scheduled",
+                      "updated_at": "2014-05-14T14:27:18.845-06:00"
+                    }
+                  ],
+                  "monitoring_at": null,
+                  "name": "Web Tier Recycle",
+                  "page_id": "kctbh9vrtdwd",
+                  "resolved_at": null,
+                  "scheduled_for": "2014-05-14T14:30:00.000-06:00",
+                  "scheduled_until": "2014-05-14T16:30:00.000-06:00",
+                  "shortlink": "http://stspg.co:5000/Q0G",
+                  "status": "in_progress",
+                  "updated_at": "2014-05-14T14:35:12.258-06:00"
+                }
+              ]
+            }"#;
+
+        let info: MaintenanceInfo = serde_json::from_str(data).unwrap();
+        assert_snapshot("active_maintenance", &render(&info, false));
+        assert_snapshot("active_maintenance_table", &render_table(&info));
+    }
+
+    #[test]
+    fn test_print_all_scheduled_maintenances() {
+        let data = r#"
+            {
+              "page":{
+                "id":"kctbh9vrtdwd",
+                "name":"GitHub",
+                "url":"https://www.githubstatus.com",
+                "updated_at": "2022-09-05T08:07:25Z"
+              },
+              "scheduled_maintenances": [
+                {
+                  "created_at": "2014-05-14T14:24:40.430-06:00",
+                  "id": "w1zdr745wmfy",
+                  "impact": "none",
+                  "incident_updates": [
+                    {
+                      "body": "Our data center has informed us that they will be performing routine network maintenance. No interruption in service is expected. Any issues during this maintenance should be directed to our support center",
+                      "created_at": "2014-05-14T14:24:41.913-06:00",
+                      "display_at": "2014-05-14T14:24:41.913-06:00",
+                      "id": "qq0vx910b3qj",
+                      "incident_id": "w1zdr745wmfy",
+                      "status": "scheduled",
+                      "updated_at": "2014-05-14T14:24:41.913-06:00"
+                    }
+                  ],
+                  "monitoring_at": null,
+                  "name": "Network Maintenance (No Interruption Expected)",
+                  "page_id": "kctbh9vrtdwd",
+                  "resolved_at": null,
+                  "scheduled_for": "2014-05-17T22:00:00.000-06:00",
+                  "scheduled_until": "2014-05-17T23:30:00.000-06:00",
+                  "shortlink": "http://stspg.co:5000/Q0F",
+                  "status": "scheduled",
+                  "updated_at": "2014-05-14T14:24:41.918-06:00"
+                },
+                {
+                  "created_at": "2014-05-14T14:27:17.303-06:00",
+                  "id": "k7mf5z1gz05c",
+                  "impact": "minor",
+                  "incident_updates": [
+                    {
+                      "body": "Scheduled maintenance is currently in progress. We will provide updates as necessary.",
+                      "created_at": "2014-05-14T14:34:20.036-06:00",
+                      "display_at": "2014-05-14T14:34:20.036-06:00",
+                      "id": "drs62w8df6fs",
+                      "incident_id": "k7mf5z1gz05c",
+                      "status": "in_progress",
+                      "updated_at": "2014-05-14T14:34:20.036-06:00"
+                    },
+                    {
+                      "body": "We will be performing rolling upgrades to our web tier with a new kernel version so that Heartbleed will stop making us lose sleep at night. Increased load and latency is expected, but the app should still function appropriately. We will provide updates every 30 minutes with progress of the reboots.",
+                      "created_at": "2014-05-14T14:27:18.845-06:00",
+                      "display_at": "2014-05-14T14:27:18.845-06:00",
+                      "id": "z40y7398jqxc",
+                      "incident_id": "k7mf5z1gz05c",
+                      "status": "scheduled",
+                      "updated_at": "2014-05-14T14:27:18.845-06:00"
+                    }
+                  ],
+                  "monitoring_at": null,
+                  "name": "Web Tier Recycle",
+                  "page_id": "kctbh9vrtdwd",
+                  "resolved_at": null,
+                  "scheduled_for": "2014-05-14T14:30:00.000-06:00",
+                  "scheduled_until": "2014-05-14T16:30:00.000-06:00",
+                  "shortlink": "http://stspg.co:5000/Q0G",
+                  "status": "in_progress",
+                  "updated_at": "2014-05-14T14:35:12.258-06:00"
+                }
+              ]
+            }"#;
+
+        let info: MaintenanceInfo = serde_json::from_str(data).unwrap();
+        assert_snapshot("all_scheduled_maintenances", &render(&info, false));
+        assert_snapshot("all_scheduled_maintenances_table", &render_table(&info));
+    }
+
+    #[test]
+    fn test_print_upcoming_maintenance() {
+        let data = r#"
+            {
+              "page":{
+                "id":"kctbh9vrtdwd",
+                "name":"GitHub",
+                "url":"https://www.githubstatus.com",
+                "updated_at": "2022-09-05T08:07:25Z"
+              },
+              "scheduled_maintenances": [
+                {
+                  "created_at": "2014-05-14T14:24:40.430-06:00",
+                  "id": "w1zdr745wmfy",
+                  "impact": "none",
+                  "incident_updates": [
+                    {
+                      "body": "Our data center has informed us that they will be performing routine network maintenance. No interruption in service is expected. Any issues during this maintenance should be directed to our support center",
+                      "created_at": "2014-05-14T14:24:41.913-06:00",
+                      "display_at": "2014-05-14T14:24:41.913-06:00",
+                      "id": "qq0vx910b3qj",
+                      "incident_id": "w1zdr745wmfy",
+                      "status": "scheduled",
+                      "updated_at": "2014-05-14T14:24:41.913-06:00"
+                    }
+                  ],
+                  "monitoring_at": null,
+                  "name": "Network Maintenance (No Interruption Expected)",
+                  "page_id": "kctbh9vrtdwd",
+                  "resolved_at": null,
+                  "scheduled_for": "2014-05-17T22:00:00.000-06:00",
+                  "scheduled_until": "2014-05-17T23:30:00.000-06:00",
+                  "shortlink": "http://stspg.co:5000/Q0F",
+                  "status": "scheduled",
+                  "updated_at": "2014-05-14T14:24:41.918-06:00"
+                }
+              ]
+            }"#;
+
+        let info: MaintenanceInfo = serde_json::from_str(data).unwrap();
+        assert_snapshot("upcoming_maintenance", &render(&info, false));
+        assert_snapshot("upcoming_maintenance_table", &render_table(&info));
+    }
+
+    #[test]
+    fn test_print_status() {
+        let data = r#"
+            {
+              "page":{
+                "id":"kctbh9vrtdwd",
+                "name":"GitHub",
+                "url":"https://www.githubstatus.com",
+                "updated_at": "2022-09-05T08:07:25Z"
+              },
+              "status": {
+                "description": "Partial System Outage",
+                "indicator": "major"
+              }
+            }"#;
+
+        let info: StatusInfo = serde_json::from_str(data).unwrap();
+        assert_snapshot("status", &render(&info, false));
+        assert_snapshot("status_table", &render_table(&info));
+    }
+
+    #[test]
+    fn test_status_json() {
+        let data = r#"
+            {
+              "page":{
+                "id":"kctbh9vrtdwd",
+                "name":"GitHub",
+                "url":"https://www.githubstatus.com",
+                "updated_at": "2022-09-05T08:07:25Z"
+              },
+              "status": {
+                "description": "Partial System Outage",
+                "indicator": "major"
+              }
+            }"#;
+
+        let info: StatusInfo = serde_json::from_str(data).unwrap();
+        let result = serde_json::to_value(&info).unwrap();
+        let expected = serde_json::json!({
+            "page": {
+                "url": "https://www.githubstatus.com",
+                "updated_at": "2022-09-05T08:07:25Z"
+            },
+            "status": {
+                "description": "Partial System Outage",
+                "indicator": "major"
+            }
+        });
+        assert_eq!(result, expected);
+        assert!(info.render_json(&mut Vec::new()).is_ok());
+    }
+
+    #[test]
+    fn test_print_summary() {
+        let data = r#"
+            {
+              "page": {
+                "id": "kctbh9vrtdwd",
+                "name": "GitHub",
+                "url": "https://www.githubstatus.com",
+                "updated_at": "2022-09-05T08:07:25Z"
+              },
+              "status": {
+                "description": "Partial System Outage",
+                "indicator": "major"
+              },
+              "components": [
+                {
+                  "created_at": "2014-05-03T01:22:07.274Z",
+                  "description": null,
+                  "id": "b13yz5g2cw10",
+                  "name": "API",
+                  "page_id": "kctbh9vrtdwd",
+                  "position": 1,
+                  "status": "partial_outage",
+                  "updated_at": "2014-05-14T20:34:43.340Z"
+                },
+                {
+                  "created_at": "2014-05-03T01:22:07.286Z",
+                  "description": null,
+                  "id": "9397cnvk62zn",
+                  "name": "Management Portal",
+                  "page_id": "kctbh9vrtdwd",
+                  "position": 2,
+                  "status": "major_outage",
+                  "updated_at": "2014-05-14T20:34:44.470Z"
+                }
+              ],
+              "incidents": [
+                {
+                  "created_at": "2014-05-14T14:22:39.441-06:00",
+                  "id": "cp306tmzcl0y",
+                  "impact": "critical",
+                  "incident_updates": [
+                    {
+                      "body": "Our master database has ham sandwiches flying out of the rack, and we're working our hardest to stop the bleeding. The whole site is down while we restore functionality, and we'll provide another update within 30 minutes.",
+                      "created_at": "2014-05-14T14:22:40.301-06:00",
+                      "display_at": "2014-05-14T14:22:40.301-06:00",
+                      "id": "jdy3tw5mt5r5",
+                      "incident_id": "cp306tmzcl0y",
+                      "status": "identified",
+                      "updated_at": "2014-05-14T14:22:40.301-06:00"
+                    }
+                  ],
+                  "monitoring_at": null,
+                  "name": "Unplanned Database Outage",
+                  "page_id": "kctbh9vrtdwd",
+                  "resolved_at": null,
+                  "shortlink": "http://stspg.co:5000/Q0E",
+                  "status": "identified",
+                  "updated_at": "2014-05-14T14:35:21.711-06:00"
+                }
+              ],
+              "scheduled_maintenances": [
+                {
+                  "created_at": "2014-05-14T14:24:40.430-06:00",
+                  "id": "w1zdr745wmfy",
+                  "impact": "none",
+                  "incident_updates": [
+                    {
+                      "body": "Our data center has informed us that they will be performing routine network maintenance. No interruption in service is expected. Any issues during this maintenance should be directed to our support center",
+                      "created_at": "2014-05-14T14:24:41.913-06:00",
+                      "display_at": "2014-05-14T14:24:41.913-06:00",
+                      "id": "qq0vx910b3qj",
+                      "incident_id": "w1zdr745wmfy",
+                      "status": "scheduled",
+                      "updated_at": "2014-05-14T14:24:41.913-06:00"
+                    }
+                  ],
+                  "monitoring_at": null,
+                  "name": "Network Maintenance (No Interruption Expected)",
+                  "page_id": "kctbh9vrtdwd",
+                  "resolved_at": null,
+                  "scheduled_for": "2014-05-17T22:00:00.000-06:00",
+                  "scheduled_until": "2014-05-17T23:30:00.000-06:00",
+                  "shortlink": "http://stspg.co:5000/Q0F",
+                  "status": "scheduled",
+                  "updated_at": "2014-05-14T14:24:41.918-06:00"
+                },
+                {
+                  "created_at": "2014-05-14T14:27:17.303-06:00",
+                  "id": "k7mf5z1gz05c",
+                  "impact": "minor",
+                  "incident_updates": [
+                    {
+                      "body": "Scheduled maintenance is currently in progress. We will provide updates as necessary.",
+                      "created_at": "2014-05-14T14:34:20.036-06:00",
+                      "display_at": "2014-05-14T14:34:20.036-06:00",
+                      "id": "drs62w8df6fs",
+                      "incident_id": "k7mf5z1gz05c",
+                      "status": "in_progress",
+                      "updated_at": "2014-05-14T14:34:20.036-06:00"
+                    },
+                    {
+                      "body": "We will be performing rolling upgrades to our web tier with a new kernel version so that Heartbleed will stop making us lose sleep at night. Increased load and latency is expected, but the app should still function appropriately. We will provide updates every 30 minutes with progress of the reboots.",
+                      "created_at": "2014-05-14T14:27:18.845-06:00",
+                      "display_at": "2014-05-14T14:27:18.845-06:00",
+                      "id": "z40y7398jqxc",
+                      "incident_id": "k7mf5z1gz05c",
+                      "status": "scheduled",
+                      "updated_at": "2014-05-14T14:27:18.845-06:00"
+                    }
+                  ],
+                  "monitoring_at": null,
+                  "name": "Web Tier Recycle",
+                  "page_id": "kctbh9vrtdwd",
+                  "resolved_at": null,
+                  "scheduled_for": "2014-05-14T14:30:00.000-06:00",
+                  "scheduled_until": "2014-05-14T16:30:00.000-06:00",
+                  "shortlink": "http://stspg.co:5000/Q0G",
+                  "status": "in_progress",
+                  "updated_at": "2014-05-14T14:35:12.258-06:00"
+                }
+              ]
+            }"#;
+
+        let info: SummaryInfo = serde_json::from_str(data).unwrap();
+        assert_snapshot("summary", &render(&info, false));
+        assert_snapshot("summary_table", &render_table(&info));
+    }
+
+    #[test]
+    fn test_print_changes() {
+        let changes = [
+            Change::Indicator {
+                old: Indicator::None,
+                new: Indicator::Minor,
+                description: "Minor Service Outage".to_string(),
+            },
+            Change::Component {
+                name: "Actions".to_string(),
+                old: ComponentStatus::Operational,
+                new: ComponentStatus::MajorOutage,
+            },
+            Change::IncidentOpened {
+                name: "Disruption with some GitHub services".to_string(),
+                impact: Impact::Minor,
+                status: IncidentStatus::Investigating,
+                shortlink: "https://stspg.io/abc123".to_string(),
+            },
+            Change::IncidentUpdated {
+                name: "Disruption with some GitHub services".to_string(),
+                old: IncidentStatus::Investigating,
+                new: IncidentStatus::Identified,
+                shortlink: "https://stspg.io/abc123".to_string(),
+            },
+            Change::IncidentResolved {
+                name: "Disruption with some GitHub services".to_string(),
+                shortlink: "https://stspg.io/abc123".to_string(),
+            },
+        ];
+
+        // Orange isn't used since it depends on the terminal's support for true color.
+        for (name, color) in [("changes", false), ("changes_color", true)] {
+            let mut out = Vec::new();
+            for change in &changes {
+                print_change(
+                    &mut out,
+                    &style(color),
+                    change,
+                    "[2022-09-05 08:07:25 UTC] ",
+                )
+                .unwrap();
+            }
+
+            assert_snapshot(name, &String::from_utf8(out).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_print_uptime_report() {
+        let data = r#"
+            [
+              {
+                "id": "1",
+                "name": "Degraded Actions",
+                "impact": "major",
+                "status": "resolved",
+                "shortlink": "https://stspg.io/1",
+                "created_at": "2024-01-10T10:00:00Z",
+                "resolved_at": "2024-01-10T12:00:00Z",
+                "components": [{"id": "a", "name": "Actions", "status": "operational"}]
+              },
+              {
+                "id": "2",
+                "name": "Slow Pages",
+                "impact": "minor",
+                "status": "resolved",
+                "shortlink": "https://stspg.io/2",
+                "created_at": "2024-01-20T11:00:00Z",
+                "resolved_at": "2024-01-20T11:45:00Z",
+                "components": [{"id": "b", "name": "Pages", "status": "operational"}]
+              }
+            ]"#;
+
+        let incidents: Vec<Incident> = serde_json::from_str(data).unwrap();
+        let report = UptimeReport::new(
+            &incidents,
+            &[],
+            "2024-01-01T00:00:00Z".parse().unwrap(),
+            "2024-01-31T00:00:00Z".parse().unwrap(),
+            &ComponentFilter::default(),
+        );
+        assert_snapshot("uptime", &render(&report, false));
+        assert_snapshot("uptime_table", &render_table(&report));
+    }
+
+    #[test]
+    fn test_print_history() {
+        let time = |value: &str| value.parse().unwrap();
+        let history = History {
+            entries: vec![
+                HistoryEntry {
+                    from: time("2024-01-10T02:00:00Z"),
+                    until: time("2024-01-10T03:05:00Z"),
+                    indicator: Indicator::None,
+                    description: "All Systems Operational".to_string(),
+                    components: vec![ComponentState {
+                        name: "Actions".to_string(),
+                        status: ComponentStatus::Operational,
+                    }],
+                },
+                HistoryEntry {
+                    from: time("2024-01-10T03:05:00Z"),
+                    until: time("2024-01-10T04:10:00Z"),
+                    indicator: Indicator::Minor,
+                    description: "Minor Service Outage".to_string(),
+                    components: vec![ComponentState {
+                        name: "Actions".to_string(),
+                        status: ComponentStatus::DegradedPerformance,
+                    }],
+                },
+            ],
+        };
+
+        assert_snapshot("history", &render(&history, false));
+        assert_snapshot("history_table", &render_table(&history));
+        assert_eq!(
+            render(&History { entries: vec![] }, false),
+            "No history was recorded for this period\n"
+        );
+    }
+}
@@ -0,0 +1,239 @@
+use std::{
+    fs,
+    io::{self, Write},
+    path::Path,
+};
+
+use anyhow::{bail, Context, Result};
+use colored::Color;
+use github_status::{ComponentFilter, Indicator, StatusClient, SummaryInfo};
+use serde::{Deserialize, Serialize};
+
+use crate::output::{Print, Style};
+
+/// A Statuspage-backed service listed in the providers file.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct Provider {
+    pub name: String,
+    pub base_url: String,
+
+    /// Only include components matching these names or glob patterns.
+    #[serde(default)]
+    pub components: Vec<String>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+struct ProvidersFile {
+    providers: Vec<Provider>,
+}
+
+/// Parses the `[[providers]]` tables in a TOML file.
+pub fn parse(data: &str) -> Result<Vec<Provider>> {
+    let file: ProvidersFile = toml::from_str(data)?;
+    if file.providers.is_empty() {
+        bail!("No providers are listed");
+    }
+
+    Ok(file.providers)
+}
+
+pub fn load(path: &Path) -> Result<Vec<Provider>> {
+    let data = fs::read_to_string(path)
+        .with_context(|| format!("Unable to read the providers from {}", path.display()))?;
+
+    parse(&data).with_context(|| format!("Invalid providers file {}", path.display()))
+}
+
+/// The summary of one provider, or why it couldn't be retrieved.
+#[derive(Serialize, Debug)]
+pub struct ProviderSummary {
+    pub name: String,
+    pub base_url: String,
+    pub summary: Option<SummaryInfo>,
+    pub error: Option<String>,
+
+    /// The worst status of the provider's selected components.
+    #[serde(skip)]
+    pub indicator: Option<Indicator>,
+}
+
+/// The summaries of every provider, shown together in one view.
+#[derive(Serialize, Debug)]
+pub struct CombinedSummary {
+    pub providers: Vec<ProviderSummary>,
+}
+
+impl CombinedSummary {
+    /// Fetches the summary of each provider. A provider that can't be retrieved is recorded
+    /// with its error instead of stopping the others.
+    pub fn fetch(providers: &[Provider], client: impl Fn(&str) -> Result<StatusClient>) -> Self {
+        let providers = providers
+            .iter()
+            .map(|provider| {
+                let filter = ComponentFilter::new(&provider.components);
+                let result = client(&provider.base_url)
+                    .and_then(|client| client.summary())
+                    .and_then(|mut summary| {
+                        summary.retain(&filter)?;
+                        Ok((summary.status.indicator.clone(), summary))
+                    });
+
+                let (summary, indicator, error) = match result {
+                    Ok((indicator, summary)) => (Some(summary), Some(indicator), None),
+                    Err(e) => (None, None, Some(e.to_string())),
+                };
+
+                ProviderSummary {
+                    name: provider.name.clone(),
+                    base_url: provider.base_url.clone(),
+                    summary,
+                    error,
+                    indicator,
+                }
+            })
+            .collect();
+
+        CombinedSummary { providers }
+    }
+
+    /// The worst indicator of all the providers, or `None` if any couldn't be retrieved.
+    pub fn indicator(&self) -> Option<Indicator> {
+        self.providers
+            .iter()
+            .map(|p| p.indicator.clone())
+            .collect::<Option<Vec<_>>>()?
+            .into_iter()
+            .max_by_key(Indicator::severity)
+    }
+
+    fn render_with(
+        &self,
+        out: &mut dyn Write,
+        style: &Style,
+        render: impl Fn(&SummaryInfo, &mut dyn Write) -> io::Result<()>,
+    ) -> io::Result<()> {
+        for (i, provider) in self.providers.iter().enumerate() {
+            if i > 0 {
+                writeln!(out)?;
+            }
+            writeln!(out, "== {} ==", provider.name)?;
+
+            match (&provider.summary, &provider.error) {
+                (Some(summary), _) => render(summary, out)?,
+                (None, error) => writeln!(
+                    out,
+                    "{}",
+                    style.paint(
+                        &format!("Error: {}", error.as_deref().unwrap_or_default()),
+                        Some(Color::Red)
+                    )
+                )?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Print for CombinedSummary {
+    fn render(&self, out: &mut dyn Write, style: &Style) -> io::Result<()> {
+        self.render_with(out, style, |summary, out| summary.render(out, style))
+    }
+
+    fn render_table(&self, out: &mut dyn Write, style: &Style) -> io::Result<()> {
+        self.render_with(out, style, |summary, out| summary.render_table(out, style))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+    use github_status::{Indicator, StatusClient};
+
+    use super::{parse, CombinedSummary, Provider};
+    use crate::output::{Print, Style};
+
+    const GITHUB: &str = r#"
+        {
+          "page": {"url": "https://www.githubstatus.com", "updated_at": null},
+          "status": {"description": "Minor Service Outage", "indicator": "minor"},
+          "components": [
+            {"id": "a", "name": "Actions", "status": "partial_outage"},
+            {"id": "b", "name": "Pages", "status": "operational"}
+          ]
+        }"#;
+
+    #[test]
+    fn test_parse() {
+        let data = r#"
+            [[providers]]
+            name = "GitHub"
+            base_url = "https://www.githubstatus.com"
+            components = ["Actions", "Git*"]
+
+            [[providers]]
+            name = "npm"
+            base_url = "https://status.npmjs.org"
+        "#;
+
+        assert_eq!(
+            parse(data).unwrap(),
+            vec![
+                Provider {
+                    name: "GitHub".to_string(),
+                    base_url: "https://www.githubstatus.com".to_string(),
+                    components: vec!["Actions".to_string(), "Git*".to_string()],
+                },
+                Provider {
+                    name: "npm".to_string(),
+                    base_url: "https://status.npmjs.org".to_string(),
+                    components: vec![],
+                },
+            ]
+        );
+
+        assert!(parse("providers = []").is_err());
+        assert!(parse("[[providers]]\nname = \"npm\"").is_err());
+    }
+
+    #[test]
+    fn test_combined_summary() {
+        let providers = [
+            Provider {
+                name: "GitHub".to_string(),
+                base_url: "github".to_string(),
+                components: vec!["Pages".to_string()],
+            },
+            Provider {
+                name: "npm".to_string(),
+                base_url: "npm".to_string(),
+                components: vec![],
+            },
+        ];
+
+        let combined = CombinedSummary::fetch(&providers, |base_url| match base_url {
+            "github" => Ok(StatusClient::default().with_input("github.json", GITHUB)),
+            _ => Err(anyhow!("Unable to connect to {base_url}")),
+        });
+
+        let mut out = Vec::new();
+        combined.render(&mut out, &Style::default()).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "== GitHub ==\n\
+             All Systems Operational\n\
+             \n\
+             Pages: operational\n\
+             \n\
+             More info: https://www.githubstatus.com\n\
+             \n\
+             == npm ==\n\
+             Error: Unable to connect to npm\n"
+        );
+
+        assert_eq!(combined.providers[0].indicator, Some(Indicator::None));
+        assert_eq!(combined.indicator(), None);
+    }
+}
@@ -0,0 +1,37 @@
+use std::{thread, time::Duration};
+
+use anyhow::Result;
+use chrono::Utc;
+use github_status::{Archive, StatusClient};
+
+use crate::output::print_error;
+
+/// Polls the status pages every `duration` and records each summary in the archive, until the
+/// process is stopped. Failed polls and writes are reported, with their causes if `verbose` is set,
+/// and retried on the next poll.
+pub fn record(
+    clients: &[StatusClient],
+    archive: &Archive,
+    duration: Duration,
+    verbose: bool,
+) -> Result<()> {
+    let mut recorders = clients
+        .iter()
+        .map(|client| archive.recorder(client.base_url()))
+        .collect::<Vec<_>>();
+
+    loop {
+        for (client, recorder) in clients.iter().zip(&mut recorders) {
+            match client.summary() {
+                Ok(summary) => {
+                    if let Err(e) = recorder.record(&summary, Utc::now()) {
+                        print_error(&e, verbose);
+                    }
+                }
+                Err(e) => print_error(&e, verbose),
+            }
+        }
+
+        thread::sleep(duration);
+    }
+}
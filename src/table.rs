@@ -0,0 +1,216 @@
+use std::io::{self, Write};
+
+use colored::Color;
+
+use crate::output::Style;
+
+/// The space between columns.
+const GAP: &str = "  ";
+
+/// The narrowest the wrapped column is made to fit the terminal.
+const MIN_WRAP_WIDTH: usize = 20;
+
+/// A table cell's text and the color to display it in.
+#[derive(Debug, Clone, Default)]
+pub struct Cell {
+    text: String,
+    color: Option<Color>,
+}
+
+impl Cell {
+    pub fn colored(text: impl Into<String>, color: Option<Color>) -> Cell {
+        Cell {
+            text: text.into(),
+            color,
+        }
+    }
+}
+
+impl From<String> for Cell {
+    fn from(text: String) -> Self {
+        Cell { text, color: None }
+    }
+}
+
+impl From<&str> for Cell {
+    fn from(text: &str) -> Self {
+        Cell::from(text.to_string())
+    }
+}
+
+/// Rows laid out in aligned columns. One column is wrapped so the table fits in the terminal.
+#[derive(Debug)]
+pub struct Table {
+    headers: Vec<&'static str>,
+    rows: Vec<Vec<Cell>>,
+    wrap: usize,
+}
+
+impl Table {
+    /// Creates a table with the `headers`, wrapping the column at index `wrap` when it is too
+    /// wide.
+    pub fn new(headers: Vec<&'static str>, wrap: usize) -> Table {
+        Table {
+            headers,
+            rows: Vec::new(),
+            wrap,
+        }
+    }
+
+    pub fn row(&mut self, cells: Vec<Cell>) {
+        self.rows.push(cells);
+    }
+
+    /// The width of each column, narrowing the wrapped column to fit `max_width` if set.
+    fn widths(&self, max_width: Option<usize>) -> Vec<usize> {
+        let mut widths: Vec<usize> = self.headers.iter().map(|h| h.chars().count()).collect();
+        for row in &self.rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.text.chars().count());
+            }
+        }
+
+        if let Some(max_width) = max_width {
+            let total = widths.iter().sum::<usize>() + GAP.len() * (widths.len() - 1);
+            if total > max_width {
+                let others = total - widths[self.wrap];
+                widths[self.wrap] = max_width
+                    .saturating_sub(others)
+                    .max(MIN_WRAP_WIDTH)
+                    .min(widths[self.wrap]);
+            }
+        }
+
+        widths
+    }
+
+    pub fn render(&self, out: &mut dyn Write, style: &Style) -> io::Result<()> {
+        let widths = self.widths(style.width);
+        let last = widths.len() - 1;
+
+        let headers: Vec<String> = self.headers.iter().map(|h| h.to_uppercase()).collect();
+        let header = headers
+            .iter()
+            .zip(&widths)
+            .map(|(h, width)| format!("{h:width$}"))
+            .collect::<Vec<_>>()
+            .join(GAP);
+        writeln!(out, "{}", header.trim_end())?;
+
+        for row in &self.rows {
+            let lines: Vec<Vec<String>> = row
+                .iter()
+                .enumerate()
+                .map(|(i, cell)| {
+                    if i == self.wrap {
+                        wrap(&cell.text, widths[i])
+                    } else {
+                        vec![cell.text.clone()]
+                    }
+                })
+                .collect();
+            let height = lines.iter().map(Vec::len).max().unwrap_or(1);
+
+            for n in 0..height {
+                let mut line = String::new();
+                for (i, cell) in row.iter().enumerate() {
+                    let text = lines[i].get(n).map(String::as_str).unwrap_or("");
+                    if i > 0 {
+                        line.push_str(GAP);
+                    }
+                    line.push_str(&style.paint(text, cell.color));
+                    if i < last {
+                        let padding = widths[i].saturating_sub(text.chars().count());
+                        line.push_str(&" ".repeat(padding));
+                    }
+                }
+                writeln!(out, "{}", line.trim_end())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Splits `text` into lines of at most `width` characters, breaking between words where possible.
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+
+    for word in text.split_whitespace() {
+        let mut word: Vec<char> = word.chars().collect();
+
+        // Words longer than a whole line are split across lines.
+        while word.len() > width {
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+            }
+            lines.push(word.drain(..width).collect());
+        }
+
+        let len = line.chars().count();
+        if len > 0 && len + 1 + word.len() > width {
+            lines.push(std::mem::take(&mut line));
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.extend(word);
+    }
+
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(line);
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{wrap, Cell, Table};
+    use crate::output::Style;
+
+    #[test]
+    fn test_wrap() {
+        assert_eq!(wrap("", 10), vec![""]);
+        assert_eq!(
+            wrap("We are investigating reports of degraded performance.", 20),
+            vec![
+                "We are investigating",
+                "reports of degraded",
+                "performance."
+            ]
+        );
+        assert_eq!(wrap("abcdefghij klm", 4), vec!["abcd", "efgh", "ij", "klm"]);
+    }
+
+    #[test]
+    fn test_render() {
+        let mut table = Table::new(vec!["Name", "Status", "Update"], 2);
+        table.row(vec![
+            Cell::from("Actions"),
+            Cell::from("partial outage"),
+            Cell::from("We are investigating reports of degraded performance."),
+        ]);
+        table.row(vec![
+            Cell::from("Git Operations"),
+            Cell::from("operational"),
+            Cell::default(),
+        ]);
+
+        let style = Style {
+            width: Some(60),
+            ..Style::default()
+        };
+        let mut out = Vec::new();
+        table.render(&mut out, &style).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "NAME            STATUS          UPDATE\n\
+             Actions         partial outage  We are investigating reports\n\
+             \x20                               of degraded performance.\n\
+             Git Operations  operational\n"
+        );
+    }
+}
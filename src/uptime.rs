@@ -0,0 +1,463 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::Serialize;
+
+use crate::archive::HistoryEntry;
+use crate::filter::ComponentFilter;
+use crate::models::{Impact, Incident, IncidentStatus};
+
+/// The number of incidents the Statuspage API returns, the most recent first.
+const INCIDENT_LIMIT: usize = 50;
+
+/// Availability over a period, computed from the incidents reported during it.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct UptimeReport {
+    /// The start of the period, which is later than `requested_since` if the incidents don't go
+    /// back that far.
+    pub since: DateTime<Utc>,
+    pub until: DateTime<Utc>,
+    pub requested_since: DateTime<Utc>,
+
+    /// The percentage of the period without an incident affecting the selected components.
+    pub availability: f64,
+
+    /// The minutes during which at least one incident was open.
+    pub degraded_minutes: i64,
+
+    /// The number of incidents for each impact, e.g. `minor`.
+    pub incidents: BTreeMap<String, usize>,
+
+    /// The mean time from an incident being created until it was resolved.
+    pub mean_time_to_resolution_minutes: Option<i64>,
+
+    /// The minutes of the period covered by the local archive.
+    pub archived_minutes: i64,
+
+    /// The components that were affected by an incident or recorded as degraded, by name.
+    pub components: Vec<ComponentUptime>,
+}
+
+/// The availability of a single component.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ComponentUptime {
+    pub name: String,
+    pub availability: f64,
+    pub degraded_minutes: i64,
+    pub incidents: usize,
+}
+
+impl UptimeReport {
+    /// Computes the report for the period from `since` until `until`. Only incidents affecting a
+    /// component selected by `filter` are included, unless it is empty. Incidents with no impact
+    /// and maintenance are counted but aren't treated as downtime.
+    ///
+    /// A component also counts as degraded while the local archive recorded it as anything but
+    /// operational or under maintenance in `history`, which covers problems never reported as
+    /// incidents.
+    ///
+    /// Only the most recent incidents are available, so if there are as many as the API returns
+    /// the period starts at the oldest of them instead.
+    pub fn new(
+        incidents: &[Incident],
+        history: &[HistoryEntry],
+        since: DateTime<Utc>,
+        until: DateTime<Utc>,
+        filter: &ComponentFilter,
+    ) -> UptimeReport {
+        let requested_since = since;
+        let since = match incidents.iter().filter_map(|i| i.created_at).min() {
+            Some(oldest) if incidents.len() >= INCIDENT_LIMIT => {
+                since.max(oldest.with_timezone(&Utc))
+            }
+            _ => since,
+        };
+
+        let mut counts = BTreeMap::new();
+        let mut downtime = Vec::new();
+        let mut resolution_times = Vec::new();
+        let mut components: BTreeMap<String, (Vec<Interval>, usize)> = BTreeMap::new();
+
+        for incident in incidents {
+            let Some(interval) = interval(incident, since, until) else {
+                continue;
+            };
+            let names: BTreeSet<&str> = affected_components(incident)
+                .into_iter()
+                .filter(|name| filter.matches(name))
+                .collect();
+            if !filter.is_empty() && names.is_empty() {
+                continue;
+            }
+
+            *counts.entry(incident.impact.to_string()).or_insert(0) += 1;
+            if let Some(time_to_resolution) = incident.time_to_resolution() {
+                resolution_times.push(time_to_resolution);
+            }
+
+            let is_outage = !matches!(incident.impact, Impact::None | Impact::Maintenance);
+            if is_outage {
+                downtime.push(interval);
+            }
+            for name in names {
+                let (intervals, count) = components.entry(name.to_string()).or_default();
+                *count += 1;
+                if is_outage {
+                    intervals.push(interval);
+                }
+            }
+        }
+
+        let mut archived = Vec::new();
+        for entry in history {
+            let interval = (entry.from.max(since), entry.until.min(until));
+            if interval.0 >= interval.1 {
+                continue;
+            }
+
+            archived.push(interval);
+            for component in entry.components.iter().filter(|c| c.is_degraded()) {
+                if filter.matches(&component.name) {
+                    downtime.push(interval);
+                    let (intervals, _) = components.entry(component.name.clone()).or_default();
+                    intervals.push(interval);
+                }
+            }
+        }
+
+        let period = until - since;
+        let degraded = merged_duration(downtime);
+
+        UptimeReport {
+            since,
+            until,
+            requested_since,
+            availability: availability(degraded, period),
+            degraded_minutes: degraded.num_minutes(),
+            incidents: counts,
+            mean_time_to_resolution_minutes: mean(&resolution_times).map(|m| m.num_minutes()),
+            archived_minutes: merged_duration(archived).num_minutes(),
+            components: components
+                .into_iter()
+                .map(|(name, (intervals, incidents))| {
+                    let degraded = merged_duration(intervals);
+                    ComponentUptime {
+                        name,
+                        availability: availability(degraded, period),
+                        degraded_minutes: degraded.num_minutes(),
+                        incidents,
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+type Interval = (DateTime<Utc>, DateTime<Utc>);
+
+/// The part of the incident that falls within the period, or `None` if it doesn't overlap it.
+fn interval(incident: &Incident, since: DateTime<Utc>, until: DateTime<Utc>) -> Option<Interval> {
+    let start = incident.created_at?.with_timezone(&Utc);
+    let end = match (&incident.resolved_at, &incident.status) {
+        (Some(resolved_at), _) => resolved_at.with_timezone(&Utc),
+        // Some resolved incidents only record when they were last updated.
+        (None, IncidentStatus::Resolved | IncidentStatus::Postmortem) => {
+            incident.updated_at?.with_timezone(&Utc)
+        }
+        (None, _) => until,
+    };
+
+    let (start, end) = (start.max(since), end.min(until));
+    (start < end).then_some((start, end))
+}
+
+/// The names of the components the incident or any of its updates affected.
+fn affected_components(incident: &Incident) -> BTreeSet<&str> {
+    let updated = incident
+        .incident_updates
+        .iter()
+        .flatten()
+        .flat_map(|u| u.affected_components.iter().flatten())
+        .map(|c| c.name.as_str());
+
+    incident
+        .components
+        .iter()
+        .map(|c| c.name.as_str())
+        .chain(updated)
+        .collect()
+}
+
+/// The total time covered by the intervals, counting overlaps once.
+fn merged_duration(mut intervals: Vec<Interval>) -> TimeDelta {
+    intervals.sort();
+
+    let mut total = TimeDelta::zero();
+    let mut current: Option<Interval> = None;
+    for (start, end) in intervals {
+        current = match current {
+            Some((s, e)) if start <= e => Some((s, e.max(end))),
+            Some((s, e)) => {
+                total += e - s;
+                Some((start, end))
+            }
+            None => Some((start, end)),
+        };
+    }
+    if let Some((s, e)) = current {
+        total += e - s;
+    }
+
+    total
+}
+
+fn availability(degraded: TimeDelta, period: TimeDelta) -> f64 {
+    if period <= TimeDelta::zero() {
+        return 100.0;
+    }
+
+    100.0 * (1.0 - degraded.num_seconds() as f64 / period.num_seconds() as f64)
+}
+
+fn mean(durations: &[TimeDelta]) -> Option<TimeDelta> {
+    let total: TimeDelta = durations.iter().sum();
+
+    (!durations.is_empty()).then(|| total / durations.len() as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, TimeDelta, Utc};
+
+    use super::{merged_duration, UptimeReport};
+    use crate::archive::{ComponentState, HistoryEntry};
+    use crate::filter::ComponentFilter;
+    use crate::models::{ComponentStatus, IncidentInfo};
+
+    const INCIDENTS: &str = r#"
+        {
+          "page": {"url": "https://www.githubstatus.com", "updated_at": null},
+          "incidents": [
+            {
+              "id": "1",
+              "name": "Degraded Actions",
+              "impact": "major",
+              "status": "resolved",
+              "shortlink": "https://stspg.io/1",
+              "created_at": "2024-01-10T10:00:00Z",
+              "resolved_at": "2024-01-10T12:00:00Z",
+              "components": [{"id": "a", "name": "Actions", "status": "operational"}]
+            },
+            {
+              "id": "2",
+              "name": "Slow Actions and Pages",
+              "impact": "minor",
+              "status": "resolved",
+              "shortlink": "https://stspg.io/2",
+              "created_at": "2024-01-10T11:00:00Z",
+              "resolved_at": "2024-01-10T13:00:00Z",
+              "components": [{"id": "a", "name": "Actions", "status": "operational"}],
+              "incident_updates": [
+                {
+                  "body": "Pages is slow too.",
+                  "status": "investigating",
+                  "created_at": "2024-01-10T11:30:00Z",
+                  "affected_components": [
+                    {"name": "Pages", "old_status": "operational", "new_status": "degraded_performance"}
+                  ]
+                }
+              ]
+            },
+            {
+              "id": "3",
+              "name": "Informational",
+              "impact": "none",
+              "status": "resolved",
+              "shortlink": "https://stspg.io/3",
+              "created_at": "2024-01-20T10:00:00Z",
+              "resolved_at": "2024-01-20T11:00:00Z"
+            },
+            {
+              "id": "4",
+              "name": "Before the period",
+              "impact": "critical",
+              "status": "resolved",
+              "shortlink": "https://stspg.io/4",
+              "created_at": "2023-12-01T10:00:00Z",
+              "resolved_at": "2023-12-01T11:00:00Z"
+            }
+          ]
+        }"#;
+
+    fn time(value: &str) -> DateTime<Utc> {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn test_new() {
+        let info: IncidentInfo = serde_json::from_str(INCIDENTS).unwrap();
+        let since = time("2024-01-01T00:00:00Z");
+        let until = time("2024-01-31T00:00:00Z");
+
+        let report = UptimeReport::new(
+            &info.incidents,
+            &[],
+            since,
+            until,
+            &ComponentFilter::default(),
+        );
+
+        assert_eq!(report.degraded_minutes, 180);
+        assert!(
+            (report.availability - (100.0 - 100.0 * 180.0 / (30.0 * 24.0 * 60.0))).abs() < 1e-9
+        );
+        assert_eq!(
+            report.incidents.into_iter().collect::<Vec<_>>(),
+            vec![
+                ("major".to_string(), 1),
+                ("minor".to_string(), 1),
+                ("none".to_string(), 1)
+            ]
+        );
+        assert_eq!(report.mean_time_to_resolution_minutes, Some(100));
+        assert_eq!(
+            report
+                .components
+                .iter()
+                .map(|c| (c.name.as_str(), c.degraded_minutes, c.incidents))
+                .collect::<Vec<_>>(),
+            vec![("Actions", 180, 2), ("Pages", 120, 1)]
+        );
+    }
+
+    #[test]
+    fn test_new_filter() {
+        let info: IncidentInfo = serde_json::from_str(INCIDENTS).unwrap();
+        let since = time("2024-01-10T12:00:00Z");
+        let until = time("2024-01-11T12:00:00Z");
+
+        let report = UptimeReport::new(
+            &info.incidents,
+            &[],
+            since,
+            until,
+            &ComponentFilter::new(["Pages"]),
+        );
+
+        assert_eq!(report.degraded_minutes, 60);
+        assert_eq!(report.components.len(), 1);
+        let pages = &report.components[0];
+        assert_eq!(pages.name, "Pages");
+        assert_eq!(pages.degraded_minutes, 60);
+        assert_eq!(pages.incidents, 1);
+        assert!((pages.availability - (100.0 - 100.0 / 24.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_with_history() {
+        let info: IncidentInfo = serde_json::from_str(INCIDENTS).unwrap();
+        let since = time("2024-01-10T00:00:00Z");
+        let until = time("2024-01-11T00:00:00Z");
+        let entry = |from: &str, until: &str, status: ComponentStatus| HistoryEntry {
+            from: time(from),
+            until: time(until),
+            indicator: status.indicator(),
+            description: String::new(),
+            components: vec![ComponentState {
+                name: "Git Operations".to_string(),
+                status,
+            }],
+        };
+        let history = [
+            entry(
+                "2024-01-10T09:00:00Z",
+                "2024-01-10T10:00:00Z",
+                ComponentStatus::Operational,
+            ),
+            entry(
+                "2024-01-10T10:00:00Z",
+                "2024-01-10T10:30:00Z",
+                ComponentStatus::MajorOutage,
+            ),
+            entry(
+                "2024-01-10T20:00:00Z",
+                "2024-01-10T20:30:00Z",
+                ComponentStatus::PartialOutage,
+            ),
+        ];
+
+        let report = UptimeReport::new(
+            &info.incidents,
+            &history,
+            since,
+            until,
+            &ComponentFilter::default(),
+        );
+
+        // The outage at 10:00 overlaps the incidents, the one at 20:00 wasn't reported.
+        assert_eq!(report.degraded_minutes, 210);
+        assert_eq!(report.archived_minutes, 120);
+        let git = report
+            .components
+            .iter()
+            .find(|c| c.name == "Git Operations")
+            .unwrap();
+        assert_eq!((git.degraded_minutes, git.incidents), (60, 0));
+    }
+
+    #[test]
+    fn test_incident_limit() {
+        // One incident a day, the oldest on the 11th.
+        let incidents = (0..50)
+            .map(|i| {
+                format!(
+                    r#"{{"id": "{i}", "name": "Incident", "impact": "minor", "status": "resolved",
+                        "shortlink": "https://stspg.io/{i}",
+                        "created_at": "{day}T10:00:00Z", "resolved_at": "{day}T11:00:00Z"}}"#,
+                    day = (time("2024-01-11T00:00:00Z") + TimeDelta::days(i)).format("%F"),
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        let info: IncidentInfo = serde_json::from_str(&format!(
+            r#"{{"page": {{"url": "https://www.githubstatus.com", "updated_at": null}},
+                "incidents": [{incidents}]}}"#
+        ))
+        .unwrap();
+        let since = time("2024-01-01T00:00:00Z");
+        let until = time("2024-03-01T10:00:00Z");
+
+        let report = UptimeReport::new(
+            &info.incidents[..49],
+            &[],
+            since,
+            until,
+            &ComponentFilter::default(),
+        );
+        assert_eq!(report.since, since);
+
+        // Older incidents might be missing, so the period starts at the oldest one.
+        let report = UptimeReport::new(
+            &info.incidents,
+            &[],
+            since,
+            until,
+            &ComponentFilter::default(),
+        );
+        assert_eq!(report.since, time("2024-01-11T10:00:00Z"));
+        assert_eq!(report.requested_since, since);
+        assert_eq!(report.degraded_minutes, 50 * 60);
+        assert!((report.availability - (100.0 - 100.0 / 24.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_merged_duration() {
+        let at = |hour: u32| time(&format!("2024-01-01T{hour:02}:00:00Z"));
+
+        assert_eq!(merged_duration(vec![]), TimeDelta::zero());
+        assert_eq!(
+            merged_duration(vec![(at(5), at(6)), (at(1), at(3)), (at(2), at(4))]),
+            TimeDelta::hours(4)
+        );
+    }
+}
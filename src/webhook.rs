@@ -0,0 +1,226 @@
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use clap::ValueEnum;
+use github_status::HttpOptions;
+use reqwest::blocking::Client;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::output::DetectedChange;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WebhookFormat {
+    /// The change as a JSON object along with a `text` description.
+    #[default]
+    Generic,
+
+    /// A Slack incoming webhook message.
+    Slack,
+
+    /// A Discord webhook message.
+    Discord,
+
+    /// A Microsoft Teams incoming webhook message.
+    Teams,
+}
+
+/// The generic payload: the change with a plain text description of it.
+#[derive(Serialize)]
+struct GenericPayload<'a> {
+    #[serde(flatten)]
+    change: &'a DetectedChange<'a>,
+
+    text: String,
+}
+
+/// Posts the changes detected by `watch` to a URL.
+#[derive(Debug)]
+pub struct Webhook {
+    pub url: String,
+    pub format: WebhookFormat,
+
+    /// The number of times to retry a failed request.
+    pub retries: u32,
+
+    /// The delay before the first retry. It doubles for each retry after that.
+    pub retry_delay: Duration,
+
+    http: Client,
+}
+
+impl Webhook {
+    pub fn new(url: String, format: WebhookFormat, retries: u32) -> Webhook {
+        Webhook {
+            url,
+            format,
+            retries,
+            retry_delay: Duration::from_secs(1),
+            http: Client::new(),
+        }
+    }
+
+    /// Sends requests with `http`, e.g. to share the proxy and certificate settings of the
+    /// status client.
+    pub fn with_client(mut self, http: Client) -> Webhook {
+        self.http = http;
+        self
+    }
+
+    fn payload(&self, change: &DetectedChange) -> Result<Value> {
+        let text = change.text();
+        let payload = match self.format {
+            WebhookFormat::Generic => serde_json::to_value(GenericPayload { change, text })?,
+            WebhookFormat::Slack | WebhookFormat::Teams => json!({ "text": text }),
+            WebhookFormat::Discord => json!({ "content": text }),
+        };
+
+        Ok(payload)
+    }
+
+    /// Posts the change, retrying with exponential backoff on connection errors, timeouts, rate
+    /// limiting, and server errors.
+    pub fn send(&self, change: &DetectedChange) -> Result<()> {
+        let payload = self.payload(change)?;
+        let retry = HttpOptions {
+            retries: self.retries,
+            retry_delay: self.retry_delay,
+            ..HttpOptions::default()
+        };
+
+        retry
+            .send(self.http.post(&self.url).json(&payload))
+            .and_then(|r| r.error_for_status())
+            .map_err(|e| anyhow!("Posting to the webhook failed: {e}"))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc::Receiver;
+    use std::time::Duration;
+
+    use chrono::{TimeZone, Utc};
+    use github_status::{Change, ComponentStatus};
+
+    use super::{Webhook, WebhookFormat};
+    use crate::fixtures::{self, empty_response, Request};
+    use crate::output::DetectedChange;
+
+    /// Responds to one request per status code and returns the webhook's URL along with a
+    /// receiver for the requests.
+    fn serve(statuses: Vec<u16>) -> (String, Receiver<Request>) {
+        let (base_url, rx) = fixtures::serve(statuses.into_iter().map(empty_response).collect());
+        (format!("{base_url}/hook"), rx)
+    }
+
+    fn detected(change: &Change) -> DetectedChange<'_> {
+        DetectedChange {
+            detected_at: Utc::now(),
+            provider: None,
+            change,
+        }
+    }
+
+    fn change() -> Change {
+        Change::Component {
+            name: "Actions".to_string(),
+            old: ComponentStatus::Operational,
+            new: ComponentStatus::PartialOutage,
+        }
+    }
+
+    #[test]
+    fn test_send_generic() {
+        let (url, rx) = serve(vec![200]);
+        let webhook = Webhook::new(url, WebhookFormat::Generic, 0);
+        let detected_at = Utc.with_ymd_and_hms(2022, 9, 5, 8, 7, 25).unwrap();
+
+        webhook
+            .send(&DetectedChange {
+                detected_at,
+                provider: None,
+                change: &change(),
+            })
+            .unwrap();
+
+        let body: serde_json::Value = serde_json::from_str(&rx.recv().unwrap().body).unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "type": "component",
+                "name": "Actions",
+                "old": "operational",
+                "new": "partial_outage",
+                "detected_at": "2022-09-05T08:07:25Z",
+                "text": "Actions: operational -> partial outage"
+            })
+        );
+    }
+
+    #[test]
+    fn test_send_slack() {
+        let (url, rx) = serve(vec![200]);
+        let webhook = Webhook::new(url, WebhookFormat::Slack, 0);
+
+        webhook.send(&detected(&change())).unwrap();
+
+        let body: serde_json::Value = serde_json::from_str(&rx.recv().unwrap().body).unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({ "text": "Actions: operational -> partial outage" })
+        );
+    }
+
+    #[test]
+    fn test_send_provider() {
+        let (url, rx) = serve(vec![200]);
+        let webhook = Webhook::new(url, WebhookFormat::Discord, 0);
+
+        webhook
+            .send(&DetectedChange {
+                provider: Some("npm"),
+                ..detected(&change())
+            })
+            .unwrap();
+
+        let body: serde_json::Value = serde_json::from_str(&rx.recv().unwrap().body).unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({ "content": "npm: Actions: operational -> partial outage" })
+        );
+    }
+
+    #[test]
+    fn test_send_retries() {
+        let (url, rx) = serve(vec![500, 503, 200]);
+        let mut webhook = Webhook::new(url, WebhookFormat::Generic, 2);
+        webhook.retry_delay = Duration::ZERO;
+
+        webhook.send(&detected(&change())).unwrap();
+        assert_eq!(rx.iter().count(), 3);
+    }
+
+    #[test]
+    fn test_send_client_error() {
+        let (url, rx) = serve(vec![404, 200]);
+        let mut webhook = Webhook::new(url, WebhookFormat::Generic, 2);
+        webhook.retry_delay = Duration::ZERO;
+
+        // A retry would have succeeded.
+        assert!(webhook.send(&detected(&change())).is_err());
+        assert_eq!(rx.try_iter().count(), 1);
+    }
+
+    #[test]
+    fn test_send_gives_up() {
+        let (url, _rx) = serve(vec![500, 500]);
+        let mut webhook = Webhook::new(url, WebhookFormat::Generic, 1);
+        webhook.retry_delay = Duration::ZERO;
+
+        assert!(webhook.send(&detected(&change())).is_err());
+    }
+}